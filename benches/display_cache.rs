@@ -0,0 +1,49 @@
+//! Compares repeated `to_string()` against the `Cached` display wrapper.
+//!
+//! Run with `cargo bench --bench display_cache`.
+
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use ethereum_mysql::{cache::Cached, sqladdress, SqlU256};
+
+const REPEAT: usize = 1_000;
+
+fn bench_display(c: &mut Criterion) {
+    let addr = sqladdress!("0x742d35Cc6635C0532925a3b8D42cC72b5c2A9A1d");
+    let value = SqlU256::from(u128::MAX);
+
+    let mut group = c.benchmark_group("display_x1000");
+    group.bench_function("address_to_string", |b| {
+        b.iter(|| {
+            (0..REPEAT)
+                .map(|_| black_box(&addr).to_string().len())
+                .sum::<usize>()
+        })
+    });
+    group.bench_function("address_cached", |b| {
+        b.iter(|| {
+            let cached = Cached::new(addr);
+            (0..REPEAT)
+                .map(|_| black_box(&cached).as_str().len())
+                .sum::<usize>()
+        })
+    });
+    group.bench_function("u256_to_string", |b| {
+        b.iter(|| {
+            (0..REPEAT)
+                .map(|_| black_box(&value).to_string().len())
+                .sum::<usize>()
+        })
+    });
+    group.bench_function("u256_cached", |b| {
+        b.iter(|| {
+            let cached = Cached::new(value);
+            (0..REPEAT)
+                .map(|_| black_box(&cached).as_str().len())
+                .sum::<usize>()
+        })
+    });
+    group.finish();
+}
+
+criterion_group!(benches, bench_display);
+criterion_main!(benches);
@@ -0,0 +1,52 @@
+//! Compares the default SipHash `HashMap` against the `ahash` aliases for address lookups.
+//!
+//! Run with `cargo bench --features ahash --bench hash_map`.
+
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use ethereum_mysql::{SqlAddress, SqlAddressMap};
+use std::collections::HashMap;
+
+const N: usize = 100_000;
+
+fn addresses() -> Vec<SqlAddress> {
+    (0..N as u64)
+        .map(|i| {
+            let mut bytes = [0u8; 20];
+            bytes[..8].copy_from_slice(&i.to_be_bytes());
+            bytes[12..].copy_from_slice(&i.wrapping_mul(0x9e37_79b9_7f4a_7c15).to_le_bytes());
+            SqlAddress::new(bytes)
+        })
+        .collect()
+}
+
+fn bench_lookups(c: &mut Criterion) {
+    let addrs = addresses();
+
+    let std_map: HashMap<SqlAddress, usize> =
+        addrs.iter().enumerate().map(|(i, a)| (*a, i)).collect();
+    let ahash_map: SqlAddressMap<usize> = addrs.iter().enumerate().map(|(i, a)| (*a, i)).collect();
+
+    let mut group = c.benchmark_group("address_lookup_100k");
+    group.bench_function("siphash", |b| {
+        b.iter(|| {
+            let mut sum = 0usize;
+            for addr in &addrs {
+                sum += std_map[black_box(addr)];
+            }
+            sum
+        })
+    });
+    group.bench_function("ahash", |b| {
+        b.iter(|| {
+            let mut sum = 0usize;
+            for addr in &addrs {
+                sum += ahash_map[black_box(addr)];
+            }
+            sum
+        })
+    });
+    group.finish();
+}
+
+criterion_group!(benches, bench_lookups);
+criterion_main!(benches);
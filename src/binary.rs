@@ -0,0 +1,284 @@
+//! Optional raw binary column storage (PostgreSQL `BYTEA`, MySQL `BINARY(N)`, SQLite `BLOB`).
+//!
+//! The wrapper types implement the sqlx traits as text, so binary storage uses separate
+//! wrappers instead of replacing those impls: [`BinaryAddress`] stores 20 raw bytes and
+//! [`BinaryFixedBytes<N>`] stores `N` raw bytes. Both encodings can be used in the same
+//! program, one per column.
+//!
+//! ```ignore
+//! sqlx::query("CREATE TABLE holders (addr BYTEA NOT NULL)").execute(&pool).await?;
+//! sqlx::query("INSERT INTO holders (addr) VALUES ($1)")
+//!     .bind(BinaryAddress::from(addr))
+//!     .execute(&pool)
+//!     .await?;
+//! let stored: BinaryAddress = sqlx::query_scalar("SELECT addr FROM holders")
+//!     .fetch_one(&pool)
+//!     .await?;
+//! ```
+#![cfg_attr(docsrs, doc(cfg(feature = "binary")))]
+
+use crate::sqlx::DecodeError;
+use crate::{SqlAddress, SqlFixedBytes};
+use sqlx_core::{
+    database::Database,
+    decode::Decode,
+    encode::{Encode, IsNull},
+    error::BoxDynError,
+    types::Type,
+};
+
+/// A [`SqlAddress`] stored as 20 raw bytes instead of hex text.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct BinaryAddress(pub SqlAddress);
+
+/// A [`SqlFixedBytes<N>`] stored as `N` raw bytes instead of hex text.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct BinaryFixedBytes<const BYTES: usize>(pub SqlFixedBytes<BYTES>);
+
+/// A 32-byte hash stored as raw bytes.
+pub type BinaryHash = BinaryFixedBytes<32>;
+
+impl BinaryAddress {
+    /// Consumes self and returns the inner `SqlAddress`.
+    pub fn into_inner(self) -> SqlAddress {
+        self.0
+    }
+}
+
+impl<const BYTES: usize> BinaryFixedBytes<BYTES> {
+    /// Consumes self and returns the inner `SqlFixedBytes`.
+    pub fn into_inner(self) -> SqlFixedBytes<BYTES> {
+        self.0
+    }
+}
+
+impl From<SqlAddress> for BinaryAddress {
+    fn from(address: SqlAddress) -> Self {
+        BinaryAddress(address)
+    }
+}
+
+impl From<BinaryAddress> for SqlAddress {
+    fn from(address: BinaryAddress) -> Self {
+        address.0
+    }
+}
+
+impl<const BYTES: usize> From<SqlFixedBytes<BYTES>> for BinaryFixedBytes<BYTES> {
+    fn from(bytes: SqlFixedBytes<BYTES>) -> Self {
+        BinaryFixedBytes(bytes)
+    }
+}
+
+impl<const BYTES: usize> From<BinaryFixedBytes<BYTES>> for SqlFixedBytes<BYTES> {
+    fn from(bytes: BinaryFixedBytes<BYTES>) -> Self {
+        bytes.0
+    }
+}
+
+// for BinaryAddress
+impl<DB: Database> Type<DB> for BinaryAddress
+where
+    Vec<u8>: Type<DB>,
+{
+    fn type_info() -> DB::TypeInfo {
+        <Vec<u8> as Type<DB>>::type_info()
+    }
+
+    fn compatible(ty: &DB::TypeInfo) -> bool {
+        <Vec<u8> as Type<DB>>::compatible(ty)
+    }
+}
+
+impl<'a, DB: Database> Encode<'a, DB> for BinaryAddress
+where
+    Vec<u8>: Encode<'a, DB>,
+{
+    fn encode_by_ref(
+        &self,
+        buf: &mut <DB as Database>::ArgumentBuffer<'a>,
+    ) -> Result<IsNull, BoxDynError> {
+        self.0.as_slice().to_vec().encode_by_ref(buf)
+    }
+}
+
+impl<'a, DB: Database> Decode<'a, DB> for BinaryAddress
+where
+    &'a [u8]: Decode<'a, DB>,
+{
+    fn decode(value: <DB as Database>::ValueRef<'a>) -> Result<Self, BoxDynError> {
+        let bytes = <&[u8]>::decode(value)?;
+        let bytes: [u8; 20] = bytes.try_into().map_err(|_| {
+            DecodeError::AddressDecodeError(format!("expected 20 bytes, got {}", bytes.len()))
+        })?;
+        Ok(BinaryAddress(SqlAddress::new(bytes)))
+    }
+}
+
+// for BinaryFixedBytes<BYTES>
+impl<const BYTES: usize, DB: Database> Type<DB> for BinaryFixedBytes<BYTES>
+where
+    Vec<u8>: Type<DB>,
+{
+    fn type_info() -> DB::TypeInfo {
+        <Vec<u8> as Type<DB>>::type_info()
+    }
+
+    fn compatible(ty: &DB::TypeInfo) -> bool {
+        <Vec<u8> as Type<DB>>::compatible(ty)
+    }
+}
+
+impl<'a, const BYTES: usize, DB: Database> Encode<'a, DB> for BinaryFixedBytes<BYTES>
+where
+    Vec<u8>: Encode<'a, DB>,
+{
+    fn encode_by_ref(
+        &self,
+        buf: &mut <DB as Database>::ArgumentBuffer<'a>,
+    ) -> Result<IsNull, BoxDynError> {
+        self.0.as_slice().to_vec().encode_by_ref(buf)
+    }
+}
+
+impl<'a, const BYTES: usize, DB: Database> Decode<'a, DB> for BinaryFixedBytes<BYTES>
+where
+    &'a [u8]: Decode<'a, DB>,
+{
+    fn decode(value: <DB as Database>::ValueRef<'a>) -> Result<Self, BoxDynError> {
+        let bytes = <&[u8]>::decode(value)?;
+        let bytes: [u8; BYTES] = bytes.try_into().map_err(|_| {
+            DecodeError::FixedBytesDecodeError(format!(
+                "expected {} bytes, got {}",
+                BYTES,
+                bytes.len()
+            ))
+        })?;
+        Ok(BinaryFixedBytes(SqlFixedBytes::new(bytes)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{sqladdress, SqlHash};
+
+    fn sample() -> (SqlAddress, SqlHash) {
+        (
+            sqladdress!("0x742d35Cc6635C0532925a3b8D42cC72b5c2A9A1d"),
+            SqlHash::from_preimage(b"binary storage"),
+        )
+    }
+
+    #[tokio::test]
+    async fn test_sqlite_blob_round_trip() {
+        let pool = sqlx::sqlite::SqlitePoolOptions::new()
+            .max_connections(1)
+            .connect("sqlite::memory:")
+            .await
+            .unwrap();
+        sqlx::query("CREATE TABLE t (addr BLOB NOT NULL, hash BLOB NOT NULL)")
+            .execute(&pool)
+            .await
+            .unwrap();
+
+        let (addr, hash) = sample();
+        sqlx::query("INSERT INTO t (addr, hash) VALUES (?, ?)")
+            .bind(BinaryAddress::from(addr))
+            .bind(BinaryHash::from(hash))
+            .execute(&pool)
+            .await
+            .unwrap();
+
+        let (len_addr, len_hash): (i64, i64) =
+            sqlx::query_as("SELECT length(addr), length(hash) FROM t")
+                .fetch_one(&pool)
+                .await
+                .unwrap();
+        assert_eq!((len_addr, len_hash), (20, 32));
+
+        let (read_addr, read_hash): (BinaryAddress, BinaryHash) =
+            sqlx::query_as("SELECT addr, hash FROM t")
+                .fetch_one(&pool)
+                .await
+                .unwrap();
+        assert_eq!(read_addr.into_inner(), addr);
+        assert_eq!(read_hash.into_inner(), hash);
+
+        // Wrong length is a decode error, not a panic.
+        let wrong: Result<BinaryAddress, _> = sqlx::query_scalar("SELECT hash FROM t")
+            .fetch_one(&pool)
+            .await;
+        assert!(matches!(wrong, Err(sqlx::Error::ColumnDecode { .. })));
+    }
+
+    #[tokio::test]
+    async fn test_postgres_bytea_round_trip() {
+        // Needs a live server; skipped unless POSTGRES_DATABASE_URL is set.
+        let Ok(url) = std::env::var("POSTGRES_DATABASE_URL") else {
+            return;
+        };
+        let pool = sqlx::postgres::PgPoolOptions::new()
+            .max_connections(1)
+            .connect(&url)
+            .await
+            .unwrap();
+        sqlx::query(
+            "CREATE TEMPORARY TABLE binary_rows (addr BYTEA NOT NULL, hash BYTEA NOT NULL)",
+        )
+        .execute(&pool)
+        .await
+        .unwrap();
+
+        let (addr, hash) = sample();
+        sqlx::query("INSERT INTO binary_rows (addr, hash) VALUES ($1, $2)")
+            .bind(BinaryAddress::from(addr))
+            .bind(BinaryHash::from(hash))
+            .execute(&pool)
+            .await
+            .unwrap();
+
+        let (read_addr, read_hash): (BinaryAddress, BinaryHash) =
+            sqlx::query_as("SELECT addr, hash FROM binary_rows")
+                .fetch_one(&pool)
+                .await
+                .unwrap();
+        assert_eq!(read_addr.0, addr);
+        assert_eq!(read_hash.0, hash);
+    }
+
+    #[tokio::test]
+    async fn test_mysql_binary_round_trip() {
+        // Needs a live server; skipped unless MYSQL_DATABASE_URL is set.
+        let Ok(url) = std::env::var("MYSQL_DATABASE_URL") else {
+            return;
+        };
+        let pool = sqlx::mysql::MySqlPoolOptions::new()
+            .max_connections(1)
+            .connect(&url)
+            .await
+            .unwrap();
+        sqlx::query(
+            "CREATE TEMPORARY TABLE binary_rows (addr BINARY(20) NOT NULL, hash BINARY(32) NOT NULL)",
+        )
+        .execute(&pool)
+        .await
+        .unwrap();
+
+        let (addr, hash) = sample();
+        sqlx::query("INSERT INTO binary_rows (addr, hash) VALUES (?, ?)")
+            .bind(BinaryAddress::from(addr))
+            .bind(BinaryHash::from(hash))
+            .execute(&pool)
+            .await
+            .unwrap();
+
+        let (read_addr, read_hash): (BinaryAddress, BinaryHash) =
+            sqlx::query_as("SELECT addr, hash FROM binary_rows")
+                .fetch_one(&pool)
+                .await
+                .unwrap();
+        assert_eq!(read_addr.0, addr);
+        assert_eq!(read_hash.0, hash);
+    }
+}
@@ -0,0 +1,95 @@
+//! A wrapper that caches the `Display` output of a value.
+//!
+//! Formatting a `SqlAddress` (EIP-55 checksum) or a `SqlU256` allocates and does
+//! real work every time. [`Cached`] computes the string once on first use and
+//! hands out the same `&str` afterwards, which helps templating code that prints
+//! the same value repeatedly.
+
+use std::cell::OnceCell;
+use std::fmt;
+use std::ops::Deref;
+
+/// A value paired with its lazily computed display string.
+///
+/// Derefs to the inner value, so all of its methods remain available.
+///
+/// # Examples
+///
+/// ```
+/// use ethereum_mysql::{cache::Cached, sqladdress};
+///
+/// let addr = Cached::new(sqladdress!("0x742d35Cc6635C0532925a3b8D42cC72b5c2A9A1d"));
+/// assert_eq!(addr.as_str(), addr.to_string());
+/// assert!(!addr.is_zero());
+/// ```
+#[derive(Debug, Clone)]
+pub struct Cached<T> {
+    value: T,
+    display: OnceCell<String>,
+}
+
+impl<T> Cached<T> {
+    /// Wraps `value`; the display string is computed on first use.
+    pub fn new(value: T) -> Self {
+        Cached {
+            value,
+            display: OnceCell::new(),
+        }
+    }
+
+    /// Returns the inner value, dropping the cached string.
+    pub fn into_inner(self) -> T {
+        self.value
+    }
+}
+
+impl<T: fmt::Display> Cached<T> {
+    /// Returns the display string, computing it on the first call.
+    pub fn as_str(&self) -> &str {
+        self.display.get_or_init(|| self.value.to_string())
+    }
+}
+
+impl<T> Deref for Cached<T> {
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        &self.value
+    }
+}
+
+impl<T> From<T> for Cached<T> {
+    fn from(value: T) -> Self {
+        Cached::new(value)
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Cached<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{sqladdress, SqlU256};
+
+    #[test]
+    fn test_cached_display() {
+        let addr = sqladdress!("0x742d35Cc6635C0532925a3b8D42cC72b5c2A9A1d");
+        let cached = Cached::new(addr);
+        assert!(cached.display.get().is_none());
+
+        let first = cached.as_str();
+        assert_eq!(first, addr.to_string());
+        // The second call returns the same allocation instead of formatting again.
+        assert!(std::ptr::eq(first, cached.as_str()));
+        assert_eq!(format!("{}", cached), addr.to_string());
+
+        let value = Cached::from(SqlU256::from(42u64));
+        assert_eq!(value.as_str(), SqlU256::from(42u64).to_string());
+        assert_eq!(*value + SqlU256::from(1u64), SqlU256::from(43u64));
+        assert_eq!(value.into_inner(), SqlU256::from(42u64));
+    }
+}
@@ -0,0 +1,80 @@
+//! Builder for ABI calldata with static arguments.
+
+use crate::{SqlAddress, SqlBytes, SqlFixedBytes, SqlU256};
+
+/// Assembles calldata from a function selector followed by 32-byte argument words.
+///
+/// Only static argument types are supported; each `push_*` call appends one word.
+///
+/// # Examples
+///
+/// ```
+/// use ethereum_mysql::{sqladdress, CallDataBuilder, SqlFixedBytes, SqlU256};
+///
+/// // transfer(address,uint256)
+/// let data = CallDataBuilder::new()
+///     .push_selector(SqlFixedBytes::new([0xa9, 0x05, 0x9c, 0xbb]))
+///     .push_address(sqladdress!("0x42066a09b94e41e0263eee935177c2ecfff7fe11"))
+///     .push_u256(SqlU256::from(1000u64))
+///     .build();
+/// assert_eq!(data.len(), 4 + 32 * 2);
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct CallDataBuilder {
+    buf: Vec<u8>,
+}
+
+impl CallDataBuilder {
+    /// Creates an empty builder.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Appends a 4-byte function selector.
+    pub fn push_selector(mut self, selector: SqlFixedBytes<4>) -> Self {
+        self.buf.extend_from_slice(selector.as_slice());
+        self
+    }
+
+    /// Appends a `uint256` word (big-endian).
+    pub fn push_u256(mut self, value: SqlU256) -> Self {
+        self.buf
+            .extend_from_slice(&value.inner().to_be_bytes::<32>());
+        self
+    }
+
+    /// Appends an `address` word (left-padded with 12 zero bytes).
+    pub fn push_address(mut self, address: SqlAddress) -> Self {
+        self.buf.extend_from_slice(address.into_word().as_slice());
+        self
+    }
+
+    /// Returns the assembled calldata.
+    pub fn build(self) -> SqlBytes {
+        SqlBytes::from(alloy::primitives::Bytes::from(self.buf))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sqladdress;
+    use std::str::FromStr;
+
+    const INPUT: &str = "0xa9059cbb00000000000000000000000042066a09b94e41e0263eee935177c2ecfff7fe11000000000000000000000000000000000000000000000002629f66e0c5300000";
+
+    #[test]
+    fn test_transfer_calldata() {
+        let data = CallDataBuilder::new()
+            .push_selector(SqlFixedBytes::from_str("0xa9059cbb").unwrap())
+            .push_address(sqladdress!("0x42066a09b94e41e0263eee935177c2ecfff7fe11"))
+            .push_u256(SqlU256::from_str("0x2629f66e0c5300000").unwrap())
+            .build();
+        assert_eq!(data, SqlBytes::from_str(INPUT).unwrap());
+    }
+
+    #[test]
+    fn test_empty() {
+        assert_eq!(CallDataBuilder::new().build(), SqlBytes::new());
+    }
+}
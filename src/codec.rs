@@ -0,0 +1,198 @@
+//! Raw byte encodings for binary wire formats (e.g. protobuf `bytes` fields).
+//!
+//! Unlike the SQL and serde integrations, these encodings carry no hex prefix:
+//! values are written as fixed-width big-endian bytes.
+//!
+//! ## prost
+//!
+//! Declare the field as `bytes` in the schema (`Vec<u8>` in prost, or `prost::bytes::Bytes`
+//! with the `bytes` option) and convert at the boundary. Decoding validates the length,
+//! so a malformed message surfaces as an error instead of a silently truncated value.
+//!
+//! ```ignore
+//! #[derive(Clone, PartialEq, prost::Message)]
+//! struct Transfer {
+//!     #[prost(bytes = "vec", tag = "1")]
+//!     to: Vec<u8>,
+//!     #[prost(bytes = "vec", tag = "2")]
+//!     value: Vec<u8>,
+//! }
+//!
+//! let msg = Transfer { to: to.to_proto_bytes(), value: value.to_proto_bytes() };
+//! let to = SqlAddress::from_proto_bytes(&msg.to)?;
+//! let value = SqlU256::from_proto_bytes(&msg.value)?;
+//! ```
+//!
+//! With the `prost` feature enabled, the wrappers also convert to and from
+//! `prost::bytes::Bytes` directly.
+
+use crate::{SqlAddress, SqlFixedBytes, SqlU256, U256};
+
+impl SqlU256 {
+    /// Encodes the value as 32 big-endian bytes.
+    ///
+    /// # Examples
+    /// ```
+    /// use ethereum_mysql::SqlU256;
+    /// let bytes = SqlU256::from(42u64).to_proto_bytes();
+    /// assert_eq!(bytes.len(), 32);
+    /// assert_eq!(bytes[31], 42);
+    /// ```
+    pub fn to_proto_bytes(&self) -> Vec<u8> {
+        self.inner().to_be_bytes::<32>().to_vec()
+    }
+
+    /// Decodes a value from exactly 32 big-endian bytes.
+    ///
+    /// Returns Err if the input is not 32 bytes long.
+    pub fn from_proto_bytes(b: &[u8]) -> Result<SqlU256, &'static str> {
+        let bytes: [u8; 32] = b
+            .try_into()
+            .map_err(|_| "SqlU256 proto bytes must be exactly 32 bytes")?;
+        Ok(SqlU256::from(U256::from_be_bytes(bytes)))
+    }
+}
+
+impl SqlAddress {
+    /// Encodes the address as its 20 raw bytes.
+    pub fn to_proto_bytes(&self) -> Vec<u8> {
+        self.as_slice().to_vec()
+    }
+
+    /// Decodes an address from exactly 20 bytes.
+    ///
+    /// Returns Err if the input is not 20 bytes long.
+    pub fn from_proto_bytes(b: &[u8]) -> Result<SqlAddress, &'static str> {
+        let bytes: [u8; 20] = b
+            .try_into()
+            .map_err(|_| "SqlAddress proto bytes must be exactly 20 bytes")?;
+        Ok(SqlAddress::new(bytes))
+    }
+}
+
+impl<const BYTES: usize> SqlFixedBytes<BYTES> {
+    /// Encodes the value as its `BYTES` raw bytes.
+    pub fn to_proto_bytes(&self) -> Vec<u8> {
+        self.as_slice().to_vec()
+    }
+
+    /// Decodes a value from exactly `BYTES` bytes.
+    ///
+    /// Returns Err if the input length does not match.
+    pub fn from_proto_bytes(b: &[u8]) -> Result<SqlFixedBytes<BYTES>, &'static str> {
+        let bytes: [u8; BYTES] = b
+            .try_into()
+            .map_err(|_| "SqlFixedBytes proto bytes have the wrong length")?;
+        Ok(SqlFixedBytes::new(bytes))
+    }
+}
+
+#[cfg(feature = "prost")]
+mod prost_bytes {
+    use super::*;
+    use prost::bytes::Bytes;
+
+    impl From<SqlU256> for Bytes {
+        fn from(value: SqlU256) -> Self {
+            Bytes::from(value.to_proto_bytes())
+        }
+    }
+
+    impl TryFrom<Bytes> for SqlU256 {
+        type Error = &'static str;
+
+        fn try_from(b: Bytes) -> Result<Self, Self::Error> {
+            SqlU256::from_proto_bytes(&b)
+        }
+    }
+
+    impl From<SqlAddress> for Bytes {
+        fn from(address: SqlAddress) -> Self {
+            Bytes::from(address.to_proto_bytes())
+        }
+    }
+
+    impl TryFrom<Bytes> for SqlAddress {
+        type Error = &'static str;
+
+        fn try_from(b: Bytes) -> Result<Self, Self::Error> {
+            SqlAddress::from_proto_bytes(&b)
+        }
+    }
+
+    impl<const BYTES: usize> From<SqlFixedBytes<BYTES>> for Bytes {
+        fn from(value: SqlFixedBytes<BYTES>) -> Self {
+            Bytes::from(value.to_proto_bytes())
+        }
+    }
+
+    impl<const BYTES: usize> TryFrom<Bytes> for SqlFixedBytes<BYTES> {
+        type Error = &'static str;
+
+        fn try_from(b: Bytes) -> Result<Self, Self::Error> {
+            SqlFixedBytes::from_proto_bytes(&b)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_proto_bytes_round_trip() {
+        for value in [
+            SqlU256::ZERO,
+            SqlU256::from(42u64),
+            SqlU256::from(U256::MAX),
+        ] {
+            let bytes = value.to_proto_bytes();
+            assert_eq!(bytes.len(), 32);
+            assert_eq!(SqlU256::from_proto_bytes(&bytes).unwrap(), value);
+        }
+
+        assert!(SqlU256::from_proto_bytes(&[0u8; 33]).is_err());
+        assert!(SqlU256::from_proto_bytes(&[0u8; 31]).is_err());
+    }
+
+    #[cfg(feature = "prost")]
+    #[test]
+    fn test_prost_message_fields() {
+        use crate::{sqladdress, SqlHash};
+        use prost::bytes::Bytes;
+        use prost::Message;
+
+        #[derive(Clone, PartialEq, prost::Message)]
+        struct Transfer {
+            #[prost(bytes = "vec", tag = "1")]
+            to: Vec<u8>,
+            #[prost(bytes = "vec", tag = "2")]
+            value: Vec<u8>,
+            #[prost(bytes = "bytes", tag = "3")]
+            tx_hash: Bytes,
+        }
+
+        let to = sqladdress!("0x42066a09b94e41e0263eee935177c2ecfff7fe11");
+        let value = SqlU256::from(1_000_000u64);
+        let tx_hash = SqlHash::splat(0xab);
+
+        let msg = Transfer {
+            to: to.to_proto_bytes(),
+            value: value.to_proto_bytes(),
+            tx_hash: tx_hash.into(),
+        };
+        let decoded = Transfer::decode(msg.encode_to_vec().as_slice()).unwrap();
+
+        assert_eq!(SqlAddress::from_proto_bytes(&decoded.to).unwrap(), to);
+        assert_eq!(SqlU256::from_proto_bytes(&decoded.value).unwrap(), value);
+        assert_eq!(SqlHash::try_from(decoded.tx_hash).unwrap(), tx_hash);
+
+        assert_eq!(SqlU256::try_from(Bytes::from(value)).unwrap(), value);
+        assert_eq!(SqlAddress::try_from(Bytes::from(to)).unwrap(), to);
+
+        // Length is validated on the way back.
+        assert!(SqlAddress::from_proto_bytes(&decoded.value).is_err());
+        assert!(SqlHash::try_from(Bytes::from_static(&[1, 2, 3])).is_err());
+        assert!(SqlU256::try_from(Bytes::new()).is_err());
+    }
+}
@@ -0,0 +1,48 @@
+//! `HashMap` aliases keyed by the wrapper types, using the faster `ahash` hasher.
+//!
+//! The wrappers hash exactly like their alloy counterparts, so these maps are
+//! drop-in replacements for the default `HashMap` in large in-memory indexes.
+#![cfg_attr(docsrs, doc(cfg(feature = "ahash")))]
+
+use crate::{SqlAddress, SqlHash, SqlU256};
+use std::collections::HashMap;
+
+/// A `HashMap` keyed by [`SqlAddress`] using `ahash`.
+///
+/// # Examples
+///
+/// ```
+/// use ethereum_mysql::{SqlAddress, SqlAddressMap};
+///
+/// let mut balances: SqlAddressMap<u64> = SqlAddressMap::default();
+/// balances.insert(SqlAddress::ZERO, 1);
+/// assert_eq!(balances[&SqlAddress::ZERO], 1);
+/// ```
+pub type SqlAddressMap<V> = HashMap<SqlAddress, V, ahash::RandomState>;
+
+/// A `HashMap` keyed by [`SqlHash`] using `ahash`.
+pub type SqlHashMap<V> = HashMap<SqlHash, V, ahash::RandomState>;
+
+/// A `HashMap` keyed by [`SqlU256`] using `ahash`.
+pub type SqlU256Map<V> = HashMap<SqlU256, V, ahash::RandomState>;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_map_aliases() {
+        let mut by_addr: SqlAddressMap<u32> = SqlAddressMap::default();
+        let mut by_hash: SqlHashMap<u32> = SqlHashMap::default();
+        let mut by_value: SqlU256Map<u32> = SqlU256Map::default();
+        for i in 0..100u8 {
+            by_addr.insert(SqlAddress::new([i; 20]), i.into());
+            by_hash.insert(SqlHash::splat(i), i.into());
+            by_value.insert(SqlU256::from(i), i.into());
+        }
+        assert_eq!(by_addr[&SqlAddress::new([7; 20])], 7);
+        assert_eq!(by_hash[&SqlHash::splat(42)], 42);
+        assert_eq!(by_value[&SqlU256::from(99u8)], 99);
+        assert_eq!(by_addr.len(), 100);
+    }
+}
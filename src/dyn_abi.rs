@@ -0,0 +1,91 @@
+//! Conversions between the wrapper types and alloy's [`DynSolValue`] for dynamic ABI encoding.
+#![cfg_attr(docsrs, doc(cfg(feature = "dyn-abi")))]
+
+use crate::{SqlAddress, SqlU256};
+use alloy::dyn_abi::DynSolValue;
+
+impl SqlU256 {
+    /// Converts the value into a `uint256` dynamic ABI value.
+    ///
+    /// # Examples
+    /// ```
+    /// use ethereum_mysql::SqlU256;
+    /// use alloy::dyn_abi::DynSolValue;
+    ///
+    /// let value = SqlU256::from(42u64).to_dyn_sol_value();
+    /// assert_eq!(value, DynSolValue::Uint(ethereum_mysql::U256::from(42u64), 256));
+    /// ```
+    pub fn to_dyn_sol_value(&self) -> DynSolValue {
+        DynSolValue::Uint(*self.inner(), 256)
+    }
+
+    /// Extracts a value from a `uintN` dynamic ABI value of any width.
+    ///
+    /// Returns Err if the value is not a `Uint`.
+    pub fn try_from_dyn_sol_value(value: &DynSolValue) -> Result<SqlU256, &'static str> {
+        match value {
+            DynSolValue::Uint(v, _) => Ok(SqlU256::from(*v)),
+            _ => Err("DynSolValue is not a Uint"),
+        }
+    }
+}
+
+impl SqlAddress {
+    /// Converts the address into an `address` dynamic ABI value.
+    pub fn to_dyn_sol_value(&self) -> DynSolValue {
+        DynSolValue::Address(*self.inner())
+    }
+
+    /// Extracts an address from an `address` dynamic ABI value.
+    ///
+    /// Returns Err if the value is not an `Address`.
+    pub fn try_from_dyn_sol_value(value: &DynSolValue) -> Result<SqlAddress, &'static str> {
+        match value {
+            DynSolValue::Address(a) => Ok(SqlAddress::from(*a)),
+            _ => Err("DynSolValue is not an Address"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{sqladdress, U256};
+    use alloy::dyn_abi::DynSolType;
+
+    #[test]
+    fn test_u256_dyn_sol_value() {
+        let value = SqlU256::from(U256::MAX);
+        let dyn_value = value.to_dyn_sol_value();
+        assert_eq!(dyn_value, DynSolValue::Uint(U256::MAX, 256));
+        assert_eq!(dyn_value.as_type(), Some(DynSolType::Uint(256)));
+        assert_eq!(dyn_value.abi_encode(), [0xffu8; 32]);
+        assert_eq!(SqlU256::try_from_dyn_sol_value(&dyn_value).unwrap(), value);
+
+        // Narrower widths decode through the same path
+        let narrow = DynSolType::Uint(64)
+            .abi_decode(&SqlU256::from(1000u64).to_dyn_sol_value().abi_encode())
+            .unwrap();
+        assert_eq!(narrow, DynSolValue::Uint(U256::from(1000u64), 64));
+        assert_eq!(
+            SqlU256::try_from_dyn_sol_value(&narrow).unwrap(),
+            SqlU256::from(1000u64)
+        );
+
+        assert!(SqlU256::try_from_dyn_sol_value(&DynSolValue::Bool(true)).is_err());
+    }
+
+    #[test]
+    fn test_address_dyn_sol_value() {
+        let addr = sqladdress!("0x742d35Cc6635C0532925a3b8D42cC72b5c2A9A1d");
+        let dyn_value = addr.to_dyn_sol_value();
+        assert_eq!(dyn_value.as_type(), Some(DynSolType::Address));
+
+        let decoded = DynSolType::Address
+            .abi_decode(&dyn_value.abi_encode())
+            .unwrap();
+        assert_eq!(SqlAddress::try_from_dyn_sol_value(&decoded).unwrap(), addr);
+
+        assert!(SqlAddress::try_from_dyn_sol_value(&SqlU256::ZERO.to_dyn_sol_value()).is_err());
+    }
+}
@@ -10,8 +10,11 @@
 //!
 //! - **SqlAddress**: Type-safe wrapper for `alloy::primitives::Address` (Ethereum address)
 //! - **SqlU256**: Wrapper for `alloy::primitives::U256` (256-bit unsigned integer) with full arithmetic and conversion support
+//! - **SqlI256**: Wrapper for `alloy::primitives::I256` (256-bit signed integer), stored as signed decimal
 //! - **`SqlFixedBytes<N>`**: Generic wrapper for fixed-size byte arrays (e.g. hashes, topics)
 //!   - **SqlHash**/**SqlTopicHash**: Type aliases for `SqlFixedBytes<32>` (commonly used for hashes/topics)
+//! - **SqlBloom**: 256-byte logs bloom with address/topic membership checks
+//! - **SqlSignature**: 65-byte `r || s || v` ECDSA signature with `r()`/`s()`/`v()` accessors
 //! - **SqlBytes**: Wrapper for dynamic-length byte arrays
 //!
 //! ## Design Highlights
@@ -19,7 +22,7 @@
 //! - **String-based storage only**: All types are stored as lowercase hex strings (with `0x` prefix) in the database for maximum compatibility and easy inspection.
 //! - **Type safety**: Compile-time and runtime validation for all Ethereum types, eliminating manual string parsing and validation in business logic.
 //! - **API ergonomics**: Direct arithmetic, comparison, and conversion with Rust primitives for U256, and compile-time address macros for zero-cost, safe usage.
-//! - **No binary mode**: Binary column support and related feature flags have been removed for simplicity and reliability.
+//! - **Text by default**: Binary columns are opt-in through the `binary` feature's separate wrapper types.
 //! - **Minimal, focused API**: Only the most practical and widely-used Ethereum types and operations are supported, with optional serde integration.
 //!
 //! ## SQLx Integration
@@ -37,7 +40,10 @@
 //! |--------------------|------------------------|
 //! | SqlAddress         | VARCHAR(42)            |
 //! | SqlU256            | VARCHAR(66)            |
+//! | SqlI256            | VARCHAR(78)            |
 //! | `SqlFixedBytes<N>` | VARCHAR(2+2*N)         |
+//! | SqlSignature       | CHAR(132)              |
+//! | SqlBloom           | CHAR(514)              |
 //! | SqlBytes           | TEXT                   |
 //!
 //! For PostgreSQL, use `TEXT` for all string types. For MySQL/SQLite, use `VARCHAR` as above.
@@ -64,28 +70,67 @@
 //!
 //! ## Migration Notes
 //!
-//! - All binary mode and related feature flags have been removed. Only string-based storage is supported.
+//! - The old binary mode feature flags have been removed; the core types always use string-based storage.
+//!   For `BYTEA`/`BINARY(N)`/`BLOB` columns, enable the `binary` feature and use the wrappers in [`binary`](crate::binary).
 //! - Update your database schema to use string (hex) columns for all Ethereum types.
 //! - See README for more details and migration guidance.
 
 #![warn(missing_docs)]
 
+mod calldata;
 mod macros;
+mod padded;
 mod sql_address;
+mod sql_bloom;
 mod sql_bytes;
 mod sql_fixed_bytes;
+mod sql_int;
+mod sql_signature;
 mod sql_uint;
 
+pub mod cache;
+pub mod codec;
+pub mod prelude;
 pub mod utils;
 
+pub use calldata::CallDataBuilder;
+pub use padded::{PaddedU256, SqlUintPadded};
 pub use sql_address::{Address, SqlAddress};
+pub use sql_bloom::SqlBloom;
 pub use sql_bytes::{Bytes, SqlBytes};
 pub use sql_fixed_bytes::{FixedBytes, SqlFixedBytes, SqlHash, SqlTopicHash};
-pub use sql_uint::{SqlU256, SqlUint, U256};
+pub use sql_int::{SqlI256, I256};
+pub use sql_signature::SqlSignature;
+pub use sql_uint::{ConversionError, ParseUintError, SqlU128, SqlU256, SqlUint, U256};
 
+#[cfg(feature = "ahash")]
+mod collections;
+#[cfg(feature = "ahash")]
+pub use collections::{SqlAddressMap, SqlHashMap, SqlU256Map};
+
+#[cfg(feature = "dyn-abi")]
+mod dyn_abi;
+
+#[cfg(feature = "tokio-postgres")]
+mod tokio_pg;
+
+#[cfg(feature = "binary")]
+pub mod binary;
+#[cfg(feature = "postgres-numeric")]
+pub mod pg_numeric;
+#[cfg(feature = "postgres-numeric")]
+pub use pg_numeric::SqlU256Numeric;
+
+#[cfg(feature = "sqlx")]
+pub mod query;
+#[cfg(feature = "serde")]
+pub mod serde;
 #[cfg(feature = "sqlx")]
 pub mod sqlx;
 
 // Re-export alloy for macro usage
 #[doc(hidden)]
 pub use alloy;
+
+/// Re-export of the `ruint` crate backing `U256`, for advanced integer operations.
+pub use alloy::primitives::ruint;
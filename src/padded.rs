@@ -0,0 +1,190 @@
+//! Zero-padded hex column storage for [`SqlUint`] values.
+//!
+//! `SqlU256` is written as minimal hex (`0xf`, `0x10`), so the database orders the string
+//! column lexically rather than numerically. [`SqlUintPadded`] writes the full-width form
+//! instead (`0x` + 64 hex digits for [`PaddedU256`]), so `ORDER BY amount` and
+//! `WHERE amount > ?` match numeric order as long as every row in the column is padded.
+//!
+//! A separate wrapper is used rather than a crate feature so the storage format is chosen
+//! per column and cannot change because some other crate in the build enabled a flag.
+//! Decoding is the same as for `SqlUint`, so unpadded legacy rows are still read.
+//!
+//! ```ignore
+//! sqlx::query("INSERT INTO balances (amount) VALUES (?)")
+//!     .bind(PaddedU256::from(amount))
+//!     .execute(&pool)
+//!     .await?;
+//! let top: Vec<PaddedU256> = sqlx::query_scalar("SELECT amount FROM balances ORDER BY amount DESC")
+//!     .fetch_all(&pool)
+//!     .await?;
+//! ```
+
+use crate::SqlUint;
+
+/// A [`SqlUint`] written as zero-padded, full-width hex.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct SqlUintPadded<const BITS: usize, const LIMBS: usize>(pub SqlUint<BITS, LIMBS>);
+
+/// A `SqlU256` stored as `0x` followed by exactly 64 hex digits.
+pub type PaddedU256 = SqlUintPadded<256, 4>;
+
+impl<const BITS: usize, const LIMBS: usize> SqlUintPadded<BITS, LIMBS> {
+    /// Consumes self and returns the inner `SqlUint`.
+    pub fn into_inner(self) -> SqlUint<BITS, LIMBS> {
+        self.0
+    }
+}
+
+impl<const BITS: usize, const LIMBS: usize> From<SqlUint<BITS, LIMBS>>
+    for SqlUintPadded<BITS, LIMBS>
+{
+    fn from(value: SqlUint<BITS, LIMBS>) -> Self {
+        SqlUintPadded(value)
+    }
+}
+
+impl<const BITS: usize, const LIMBS: usize> From<SqlUintPadded<BITS, LIMBS>>
+    for SqlUint<BITS, LIMBS>
+{
+    fn from(value: SqlUintPadded<BITS, LIMBS>) -> Self {
+        value.0
+    }
+}
+
+impl<const BITS: usize, const LIMBS: usize> std::fmt::Display for SqlUintPadded<BITS, LIMBS> {
+    /// Formats as the stored form, e.g. 66 characters for `PaddedU256`.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:+}", self.0)
+    }
+}
+
+#[cfg(feature = "sqlx")]
+mod sqlx_impls {
+    use super::*;
+    use sqlx_core::{
+        database::Database,
+        decode::Decode,
+        encode::{Encode, IsNull},
+        error::BoxDynError,
+        types::Type,
+    };
+
+    impl<const BITS: usize, const LIMBS: usize, DB: Database> Type<DB> for SqlUintPadded<BITS, LIMBS>
+    where
+        String: Type<DB>,
+    {
+        fn type_info() -> DB::TypeInfo {
+            <String as Type<DB>>::type_info()
+        }
+
+        fn compatible(ty: &DB::TypeInfo) -> bool {
+            <String as Type<DB>>::compatible(ty)
+        }
+    }
+
+    impl<'a, const BITS: usize, const LIMBS: usize, DB: Database> Encode<'a, DB>
+        for SqlUintPadded<BITS, LIMBS>
+    where
+        String: Encode<'a, DB>,
+    {
+        fn encode_by_ref(
+            &self,
+            buf: &mut <DB as Database>::ArgumentBuffer<'a>,
+        ) -> Result<IsNull, BoxDynError> {
+            self.to_string().encode_by_ref(buf)
+        }
+    }
+
+    impl<'a, const BITS: usize, const LIMBS: usize, DB: Database> Decode<'a, DB>
+        for SqlUintPadded<BITS, LIMBS>
+    where
+        String: Decode<'a, DB>,
+    {
+        fn decode(value: <DB as Database>::ValueRef<'a>) -> Result<Self, BoxDynError> {
+            <SqlUint<BITS, LIMBS> as Decode<'a, DB>>::decode(value).map(SqlUintPadded)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::SqlU256;
+
+    #[test]
+    fn test_display_is_full_width() {
+        let value = PaddedU256::from(SqlU256::from(255u64));
+        assert_eq!(value.to_string(), format!("0x{}ff", "0".repeat(62)));
+        assert_eq!(value.into_inner().to_string(), "0xff");
+    }
+
+    #[cfg(feature = "sqlx")]
+    #[tokio::test]
+    async fn test_padded_orders_numerically() {
+        let pool = sqlx::sqlite::SqlitePoolOptions::new()
+            .max_connections(1)
+            .connect("sqlite::memory:")
+            .await
+            .unwrap();
+        sqlx::query("CREATE TABLE t (amount VARCHAR(66) NOT NULL)")
+            .execute(&pool)
+            .await
+            .unwrap();
+
+        let values = [0x100u64, 0x0, 0xff, 0x1000, 0xf];
+        for v in values {
+            sqlx::query("INSERT INTO t (amount) VALUES (?)")
+                .bind(PaddedU256::from(SqlU256::from(v)))
+                .execute(&pool)
+                .await
+                .unwrap();
+        }
+
+        let raw: String = sqlx::query_scalar("SELECT amount FROM t WHERE rowid = 3")
+            .fetch_one(&pool)
+            .await
+            .unwrap();
+        assert_eq!(raw, format!("0x{}ff", "0".repeat(62)));
+
+        let sorted: Vec<PaddedU256> = sqlx::query_scalar("SELECT amount FROM t ORDER BY amount")
+            .fetch_all(&pool)
+            .await
+            .unwrap();
+        let mut expected: Vec<SqlU256> = values.iter().map(|v| SqlU256::from(*v)).collect();
+        expected.sort();
+        assert_eq!(
+            sorted.into_iter().map(SqlU256::from).collect::<Vec<_>>(),
+            expected
+        );
+
+        let above: Vec<SqlU256> =
+            sqlx::query_scalar("SELECT amount FROM t WHERE amount > ? ORDER BY amount")
+                .bind(PaddedU256::from(SqlU256::from(0xfu64)))
+                .fetch_all(&pool)
+                .await
+                .unwrap();
+        assert_eq!(
+            above,
+            vec![
+                SqlU256::from(0xffu64),
+                SqlU256::from(0x100u64),
+                SqlU256::from(0x1000u64)
+            ]
+        );
+
+        // Plain SqlU256 still writes minimal hex, and a legacy unpadded row decodes
+        // through the padded wrapper.
+        sqlx::query("INSERT INTO t (amount) VALUES (?)")
+            .bind(SqlU256::from(42u64))
+            .execute(&pool)
+            .await
+            .unwrap();
+        let legacy: (String, PaddedU256) =
+            sqlx::query_as("SELECT amount, amount FROM t WHERE rowid = 6")
+                .fetch_one(&pool)
+                .await
+                .unwrap();
+        assert_eq!(legacy.0, "0x2a");
+        assert_eq!(legacy.1 .0, SqlU256::from(42u64));
+    }
+}
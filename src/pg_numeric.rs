@@ -0,0 +1,281 @@
+//! Native PostgreSQL `NUMERIC` storage for [`SqlU256`].
+//!
+//! The default sqlx impls store `SqlU256` as hex text, which keeps columns portable but
+//! prevents `SUM(balance)` or `WHERE balance > 1000` from working in the database.
+//! [`SqlU256Numeric`] binds and reads the full 256-bit value as a `NUMERIC(78,0)` instead.
+//!
+//! A separate wrapper is needed because the text encoding already implements sqlx traits
+//! for every database, including PostgreSQL.
+//!
+//! ```ignore
+//! sqlx::query("CREATE TABLE balances (balance NUMERIC(78,0) NOT NULL)").execute(&pool).await?;
+//! sqlx::query("INSERT INTO balances (balance) VALUES ($1)")
+//!     .bind(SqlU256Numeric::from(balance))
+//!     .execute(&pool)
+//!     .await?;
+//! let total: SqlU256Numeric = sqlx::query_scalar("SELECT SUM(balance) FROM balances")
+//!     .fetch_one(&pool)
+//!     .await?;
+//! ```
+#![cfg_attr(docsrs, doc(cfg(feature = "postgres-numeric")))]
+
+use crate::{SqlU256, U256};
+use sqlx_core::{
+    decode::Decode,
+    encode::{Encode, IsNull},
+    error::BoxDynError,
+    types::Type,
+};
+use sqlx_postgres::{
+    types::Oid, PgArgumentBuffer, PgTypeInfo, PgValueFormat, PgValueRef, Postgres,
+};
+use std::str::FromStr;
+
+/// Built-in OID of the PostgreSQL `NUMERIC` type.
+const NUMERIC_OID: Oid = Oid(1700);
+/// `NUMERIC` digits are stored in base 10000.
+const NBASE: u64 = 10_000;
+const SIGN_POS: u16 = 0x0000;
+const SIGN_NEG: u16 = 0x4000;
+const SIGN_NAN: u16 = 0xC000;
+
+/// A [`SqlU256`] stored as a PostgreSQL `NUMERIC` instead of hex text.
+///
+/// Decoding rejects `NaN`, negative values, values with a non-zero fractional part, and
+/// values that do not fit in 256 bits.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct SqlU256Numeric(pub SqlU256);
+
+impl SqlU256Numeric {
+    /// Consumes self and returns the inner `SqlU256`.
+    pub fn into_inner(self) -> SqlU256 {
+        self.0
+    }
+}
+
+impl From<SqlU256> for SqlU256Numeric {
+    fn from(value: SqlU256) -> Self {
+        SqlU256Numeric(value)
+    }
+}
+
+impl From<SqlU256Numeric> for SqlU256 {
+    fn from(value: SqlU256Numeric) -> Self {
+        value.0
+    }
+}
+
+/// Splits a value into base-10000 digits (most significant first, trailing zero digits
+/// trimmed) and the weight of the first digit.
+fn to_base10000(value: U256) -> (Vec<i16>, i16) {
+    let base = U256::from(NBASE);
+    let mut digits = Vec::new();
+    let mut rest = value;
+    while !rest.is_zero() {
+        digits.push((rest % base).to::<u64>() as i16);
+        rest /= base;
+    }
+    if digits.is_empty() {
+        return (digits, 0);
+    }
+    let weight = digits.len() as i16 - 1;
+    let trailing = digits.iter().take_while(|d| **d == 0).count();
+    digits.drain(..trailing);
+    digits.reverse();
+    (digits, weight)
+}
+
+/// Rebuilds an integer from base-10000 digits; fractional digits must be zero.
+fn from_base10000(digits: &[i16], weight: i16) -> Result<U256, &'static str> {
+    let base = U256::from(NBASE);
+    let integer_digits = usize::try_from(i32::from(weight) + 1).unwrap_or(0);
+    let mut value = U256::ZERO;
+    for i in 0..integer_digits {
+        let digit = *digits.get(i).unwrap_or(&0);
+        if !(0..NBASE as i16).contains(&digit) {
+            return Err("NUMERIC digit out of range");
+        }
+        value = value
+            .checked_mul(base)
+            .and_then(|v| v.checked_add(U256::from(digit as u64)))
+            .ok_or("NUMERIC value does not fit in 256 bits")?;
+    }
+    if digits.iter().skip(integer_digits).any(|d| *d != 0) {
+        return Err("NUMERIC value has a fractional part");
+    }
+    Ok(value)
+}
+
+/// Parses the text form of a `NUMERIC` (e.g. `"123"` or `"123.000"`).
+fn from_numeric_text(s: &str) -> Result<SqlU256, &'static str> {
+    let (int_part, frac_part) = s.split_once('.').unwrap_or((s, ""));
+    if !frac_part.bytes().all(|b| b == b'0') {
+        return Err("NUMERIC value has a fractional part");
+    }
+    if !int_part.bytes().all(|b| b.is_ascii_digit()) || int_part.is_empty() {
+        return Err("NUMERIC value is not a non-negative integer");
+    }
+    SqlU256::from_str(int_part).map_err(|_| "NUMERIC value does not fit in 256 bits")
+}
+
+impl Type<Postgres> for SqlU256Numeric {
+    fn type_info() -> PgTypeInfo {
+        PgTypeInfo::with_oid(NUMERIC_OID)
+    }
+}
+
+impl Encode<'_, Postgres> for SqlU256Numeric {
+    fn encode_by_ref(&self, buf: &mut PgArgumentBuffer) -> Result<IsNull, BoxDynError> {
+        let (digits, weight) = to_base10000(*self.0.inner());
+        buf.extend(&(digits.len() as i16).to_be_bytes());
+        buf.extend(&weight.to_be_bytes());
+        buf.extend(&SIGN_POS.to_be_bytes());
+        buf.extend(&0i16.to_be_bytes());
+        for digit in digits {
+            buf.extend(&digit.to_be_bytes());
+        }
+        Ok(IsNull::No)
+    }
+}
+
+impl Decode<'_, Postgres> for SqlU256Numeric {
+    fn decode(value: PgValueRef<'_>) -> Result<Self, BoxDynError> {
+        if value.format() == PgValueFormat::Text {
+            return Ok(SqlU256Numeric(from_numeric_text(value.as_str()?)?));
+        }
+
+        let buf = value.as_bytes()?;
+        let read = |i: usize| -> Result<[u8; 2], BoxDynError> {
+            buf.get(i * 2..i * 2 + 2)
+                .and_then(|b| b.try_into().ok())
+                .ok_or_else(|| "truncated NUMERIC value".into())
+        };
+        let num_digits = u16::from_be_bytes(read(0)?) as usize;
+        let weight = i16::from_be_bytes(read(1)?);
+        let sign = u16::from_be_bytes(read(2)?);
+        let digits = (0..num_digits)
+            .map(|i| read(4 + i).map(i16::from_be_bytes))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        match sign {
+            SIGN_NAN => Err("NUMERIC NaN cannot be decoded as SqlU256".into()),
+            SIGN_NEG if digits.iter().any(|d| *d != 0) => {
+                Err("negative NUMERIC cannot be decoded as SqlU256".into())
+            }
+            SIGN_POS | SIGN_NEG => Ok(SqlU256Numeric(SqlU256::from(from_base10000(
+                &digits, weight,
+            )?))),
+            _ => Err("invalid NUMERIC sign".into()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_base10000_round_trip() {
+        let cases = [
+            (U256::ZERO, vec![], 0),
+            (U256::from(1u64), vec![1], 0),
+            (U256::from(10_000u64), vec![1], 1),
+            (U256::from(123_456_789u64), vec![1, 2345, 6789], 2),
+            (U256::from(10u64).pow(U256::from(20u64)), vec![1], 5),
+        ];
+        for (value, digits, weight) in cases {
+            assert_eq!(to_base10000(value), (digits.clone(), weight));
+            assert_eq!(from_base10000(&digits, weight).unwrap(), value);
+        }
+
+        // 2^256 - 1 has 78 decimal digits, i.e. 20 base-10000 digits
+        let (digits, weight) = to_base10000(U256::MAX);
+        assert_eq!(weight, 19);
+        assert_eq!(from_base10000(&digits, weight).unwrap(), U256::MAX);
+    }
+
+    #[test]
+    fn test_base10000_rejects_invalid() {
+        // Fractional part: 1.5
+        assert!(from_base10000(&[1, 5000], 0).is_err());
+        // Trailing zero fractional digits are fine: 1.0000
+        assert_eq!(from_base10000(&[1, 0], 0).unwrap(), U256::from(1u64));
+        // 0.5
+        assert!(from_base10000(&[5000], -1).is_err());
+        // 10^80 overflows 256 bits
+        assert!(from_base10000(&[1], 20).is_err());
+        assert!(from_base10000(&[10_000], 0).is_err());
+    }
+
+    #[test]
+    fn test_encode_wire_format() {
+        let mut buf = PgArgumentBuffer::default();
+        let value = SqlU256Numeric::from(SqlU256::from(123_456_789u64));
+        assert!(matches!(value.encode_by_ref(&mut buf), Ok(IsNull::No)));
+        // ndigits=3, weight=2, sign=+, dscale=0, digits 1 2345 6789
+        assert_eq!(
+            buf.as_slice(),
+            &[0, 3, 0, 2, 0, 0, 0, 0, 0, 1, 0x09, 0x29, 0x1a, 0x85]
+        );
+    }
+
+    #[test]
+    fn test_numeric_text() {
+        assert_eq!(from_numeric_text("0"), Ok(SqlU256::ZERO));
+        assert_eq!(from_numeric_text("42.000"), Ok(SqlU256::from(42u64)));
+        assert!(from_numeric_text("42.5").is_err());
+        assert!(from_numeric_text("-1").is_err());
+        assert!(from_numeric_text("NaN").is_err());
+        assert!(from_numeric_text("0x2a").is_err());
+    }
+
+    #[tokio::test]
+    async fn test_sum_aggregation() {
+        // Needs a live server; skipped unless POSTGRES_DATABASE_URL is set.
+        let Ok(url) = std::env::var("POSTGRES_DATABASE_URL") else {
+            return;
+        };
+        let pool = sqlx::postgres::PgPoolOptions::new()
+            .max_connections(1)
+            .connect(&url)
+            .await
+            .unwrap();
+        sqlx::query("CREATE TEMPORARY TABLE numeric_balances (balance NUMERIC(78,0) NOT NULL)")
+            .execute(&pool)
+            .await
+            .unwrap();
+
+        let values = [
+            SqlU256::from(1u64),
+            SqlU256::from(u128::MAX),
+            SqlU256::from(U256::MAX / U256::from(4u64)),
+            SqlU256::from(10u64).pow(30),
+        ];
+        for value in values {
+            sqlx::query("INSERT INTO numeric_balances (balance) VALUES ($1)")
+                .bind(SqlU256Numeric::from(value))
+                .execute(&pool)
+                .await
+                .unwrap();
+        }
+
+        let total: SqlU256Numeric = sqlx::query_scalar("SELECT SUM(balance) FROM numeric_balances")
+            .fetch_one(&pool)
+            .await
+            .unwrap();
+        assert_eq!(total.into_inner(), values.iter().sum::<SqlU256>());
+
+        let above: i64 =
+            sqlx::query_scalar("SELECT COUNT(*) FROM numeric_balances WHERE balance > 1000")
+                .fetch_one(&pool)
+                .await
+                .unwrap();
+        assert_eq!(above, 3);
+
+        let max: SqlU256Numeric = sqlx::query_scalar("SELECT MAX(balance) FROM numeric_balances")
+            .fetch_one(&pool)
+            .await
+            .unwrap();
+        assert_eq!(max.0, values[2]);
+    }
+}
@@ -0,0 +1,25 @@
+//! Convenience re-exports of the most commonly used types, macros and traits.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use ethereum_mysql::prelude::*;
+//!
+//! let addr: SqlAddress = sqladdress!("0x742d35Cc6635C0532925a3b8D42cC72b5c2A9A1d");
+//! let parsed = SqlAddress::from_str("0x742d35Cc6635C0532925a3b8D42cC72b5c2A9A1d").unwrap();
+//! assert_eq!(addr, parsed);
+//!
+//! let amount: SqlU256 = sqlu256!(1000);
+//! assert_eq!(amount, SqlU256::from_str("1000").unwrap());
+//!
+//! let hash: SqlHash = sqlhash!(32, "0xddf252ad1be2c89b69c2b068fc378daa952ba7f163c4a11628f55a4df523b3ef");
+//! let topic: SqlTopicHash = hash;
+//! assert_eq!(topic, SqlTopicHash::from_str(&hash.to_string()).unwrap());
+//!
+//! let data = SqlBytes::from_str("0xa9059cbb").unwrap();
+//! assert_eq!(data.len(), 4);
+//! ```
+
+pub use crate::{sqladdress, sqlhash, sqlu256};
+pub use crate::{SqlAddress, SqlBytes, SqlHash, SqlTopicHash, SqlU256};
+pub use std::str::FromStr;
@@ -0,0 +1,324 @@
+//! This module is only available when the `sqlx` feature is enabled.
+//! Small helpers for building SQL text around the wrapper types.
+#![cfg_attr(docsrs, doc(cfg(feature = "sqlx")))]
+
+use crate::SqlU256;
+
+/// SQL placeholder style of a database backend.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Dialect {
+    /// MySQL: `?` placeholders.
+    MySql,
+    /// SQLite: `?` placeholders.
+    Sqlite,
+    /// PostgreSQL: numbered `$1`, `$2`, ... placeholders.
+    Postgres,
+}
+
+impl Dialect {
+    /// Returns the placeholder for the 1-based parameter `index`.
+    fn placeholder(self, index: usize) -> String {
+        match self {
+            Dialect::MySql | Dialect::Sqlite => "?".to_string(),
+            Dialect::Postgres => format!("${}", index),
+        }
+    }
+}
+
+/// Builds `WHERE` fragments that compare a `SqlU256` column numerically.
+///
+/// Values are stored as minimal `0x` hex (`0xf`, `0x10`), so comparing the raw column as
+/// a string orders `0xf` after `0x10`. The fragments produced here left-pad the column to
+/// 64 hex digits inside the query and bind a 66-char padded value, which makes string
+/// comparison match numeric comparison.
+///
+/// The builder tracks the parameter index, so PostgreSQL placeholders stay numbered
+/// correctly across several conditions.
+///
+/// Only the values are bound; `column` is written into the SQL text as-is. It must be a
+/// trusted identifier, never user input, and every method returns Err unless it matches
+/// `[A-Za-z_][A-Za-z0-9_.]*` (e.g. `balance` or `t.balance`).
+///
+/// # Examples
+///
+/// ```
+/// use ethereum_mysql::{query::{Dialect, U256Filter}, SqlU256};
+///
+/// let mut filter = U256Filter::new(Dialect::Postgres);
+/// let (sql, bind) = filter.gt("balance", SqlU256::from(255u64)).unwrap();
+/// assert_eq!(sql, "('0x' || LPAD(SUBSTRING(balance FROM 3), 64, '0')) > $1");
+/// assert_eq!(bind.len(), 66);
+/// assert!(filter.gt("balance; DROP TABLE t", SqlU256::ZERO).is_err());
+/// ```
+#[derive(Debug, Clone)]
+pub struct U256Filter {
+    dialect: Dialect,
+    next_param: usize,
+}
+
+impl U256Filter {
+    /// Creates a filter builder for `dialect`, starting at parameter 1.
+    pub fn new(dialect: Dialect) -> Self {
+        U256Filter {
+            dialect,
+            next_param: 1,
+        }
+    }
+
+    /// Formats `value` as the 66-char zero-padded hex bind value.
+    pub fn padded(value: SqlU256) -> String {
+        value.to_hex_padded(64)
+    }
+
+    /// `column > value`
+    pub fn gt(&mut self, column: &str, value: SqlU256) -> Result<(String, String), &'static str> {
+        self.compare(column, ">", value)
+    }
+
+    /// `column >= value`
+    pub fn gte(&mut self, column: &str, value: SqlU256) -> Result<(String, String), &'static str> {
+        self.compare(column, ">=", value)
+    }
+
+    /// `column < value`
+    pub fn lt(&mut self, column: &str, value: SqlU256) -> Result<(String, String), &'static str> {
+        self.compare(column, "<", value)
+    }
+
+    /// `column <= value`
+    pub fn lte(&mut self, column: &str, value: SqlU256) -> Result<(String, String), &'static str> {
+        self.compare(column, "<=", value)
+    }
+
+    /// `column BETWEEN low AND high` (inclusive); returns both bind values in order.
+    pub fn between(
+        &mut self,
+        column: &str,
+        low: SqlU256,
+        high: SqlU256,
+    ) -> Result<(String, (String, String)), &'static str> {
+        let expr = self.padded_column(column)?;
+        let low_param = self.param();
+        let high_param = self.param();
+        Ok((
+            format!("{} BETWEEN {} AND {}", expr, low_param, high_param),
+            (Self::padded(low), Self::padded(high)),
+        ))
+    }
+
+    fn compare(
+        &mut self,
+        column: &str,
+        op: &str,
+        value: SqlU256,
+    ) -> Result<(String, String), &'static str> {
+        let expr = self.padded_column(column)?;
+        let param = self.param();
+        Ok((format!("{} {} {}", expr, op, param), Self::padded(value)))
+    }
+
+    /// SQL expression left-padding the hex digits of `column` to 64 characters.
+    fn padded_column(&self, column: &str) -> Result<String, &'static str> {
+        if !is_identifier(column) {
+            return Err("column must be a plain identifier");
+        }
+        Ok(match self.dialect {
+            Dialect::MySql => format!("CONCAT('0x', LPAD(SUBSTRING({}, 3), 64, '0'))", column),
+            Dialect::Postgres => {
+                format!("('0x' || LPAD(SUBSTRING({} FROM 3), 64, '0'))", column)
+            }
+            // SQLite has no LPAD: prepend 64 zeros and keep the last 64 characters.
+            Dialect::Sqlite => format!(
+                "('0x' || SUBSTR('{}' || SUBSTR({}, 3), -64, 64))",
+                "0".repeat(64),
+                column
+            ),
+        })
+    }
+
+    fn param(&mut self) -> String {
+        let param = self.dialect.placeholder(self.next_param);
+        self.next_param += 1;
+        param
+    }
+}
+
+/// Returns true if `s` matches `[A-Za-z_][A-Za-z0-9_.]*`.
+fn is_identifier(s: &str) -> bool {
+    let mut chars = s.chars();
+    matches!(chars.next(), Some(c) if c.is_ascii_alphabetic() || c == '_')
+        && chars.all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '.')
+}
+
+impl SqlU256 {
+    /// Builds the placeholder list for a `WHERE col IN (...)` clause with `n` values.
+    ///
+    /// Bind the values in order after formatting the query. An `n` of 0 yields `()`,
+    /// which is not valid SQL, so callers should handle empty inputs separately.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ethereum_mysql::{query::Dialect, SqlU256};
+    ///
+    /// assert_eq!(SqlU256::in_clause_placeholders(3, Dialect::MySql), "(?, ?, ?)");
+    /// assert_eq!(SqlU256::in_clause_placeholders(2, Dialect::Postgres), "($1, $2)");
+    /// ```
+    pub fn in_clause_placeholders(n: usize, dialect: Dialect) -> String {
+        let placeholders: Vec<String> = (1..=n).map(|i| dialect.placeholder(i)).collect();
+        format!("({})", placeholders.join(", "))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_in_clause_placeholders() {
+        assert_eq!(SqlU256::in_clause_placeholders(1, Dialect::MySql), "(?)");
+        assert_eq!(
+            SqlU256::in_clause_placeholders(3, Dialect::MySql),
+            "(?, ?, ?)"
+        );
+        assert_eq!(
+            SqlU256::in_clause_placeholders(3, Dialect::Sqlite),
+            "(?, ?, ?)"
+        );
+        assert_eq!(
+            SqlU256::in_clause_placeholders(1, Dialect::Postgres),
+            "($1)"
+        );
+        assert_eq!(
+            SqlU256::in_clause_placeholders(3, Dialect::Postgres),
+            "($1, $2, $3)"
+        );
+        assert_eq!(SqlU256::in_clause_placeholders(0, Dialect::Postgres), "()");
+    }
+
+    #[test]
+    fn test_u256_filter_fragments() {
+        let value = SqlU256::from(255u64);
+        let padded = format!("0x{}ff", "0".repeat(62));
+
+        let mut mysql = U256Filter::new(Dialect::MySql);
+        let (sql, bind) = mysql.gt("balance", value).unwrap();
+        assert_eq!(
+            sql,
+            "CONCAT('0x', LPAD(SUBSTRING(balance, 3), 64, '0')) > ?"
+        );
+        assert_eq!(bind, padded);
+        assert_eq!(bind.len(), 66);
+
+        let mut pg = U256Filter::new(Dialect::Postgres);
+        let (sql, bind) = pg.gt("balance", value).unwrap();
+        assert_eq!(
+            sql,
+            "('0x' || LPAD(SUBSTRING(balance FROM 3), 64, '0')) > $1"
+        );
+        assert_eq!(bind, padded);
+        let (sql, (low, high)) = pg.between("balance", SqlU256::from(1u64), value).unwrap();
+        assert_eq!(
+            sql,
+            "('0x' || LPAD(SUBSTRING(balance FROM 3), 64, '0')) BETWEEN $2 AND $3"
+        );
+        assert_eq!(low, format!("0x{}1", "0".repeat(63)));
+        assert_eq!(high, padded);
+    }
+
+    #[test]
+    fn test_u256_filter_rejects_non_identifier_columns() {
+        let mut filter = U256Filter::new(Dialect::Postgres);
+        for column in [
+            "",
+            "1balance",
+            "balance; DROP TABLE t",
+            "balance)",
+            "bal ance",
+            "'0x'",
+        ] {
+            assert!(filter.gt(column, SqlU256::ZERO).is_err(), "{column}");
+            assert!(filter
+                .between(column, SqlU256::ZERO, SqlU256::ZERO)
+                .is_err());
+        }
+
+        // Rejected columns do not consume a parameter index.
+        let (sql, _) = filter.lte("t.balance_2", SqlU256::ZERO).unwrap();
+        assert!(sql.starts_with("('0x' || LPAD(SUBSTRING(t.balance_2 FROM 3)"));
+        assert!(sql.ends_with("<= $1"));
+        assert!(filter.gte("_balance", SqlU256::ZERO).is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_u256_filter_orders_numerically_on_sqlite() {
+        let pool = sqlx::sqlite::SqlitePoolOptions::new()
+            .max_connections(1)
+            .connect("sqlite::memory:")
+            .await
+            .unwrap();
+        sqlx::query("CREATE TABLE t (balance VARCHAR(66) NOT NULL)")
+            .execute(&pool)
+            .await
+            .unwrap();
+        for v in [0x0fu64, 0x10, 0xff, 0x100] {
+            sqlx::query("INSERT INTO t (balance) VALUES (?)")
+                .bind(SqlU256::from(v))
+                .execute(&pool)
+                .await
+                .unwrap();
+        }
+
+        // A plain string comparison would treat "0xf" as greater than "0x10".
+        let mut filter = U256Filter::new(Dialect::Sqlite);
+        let (sql, bind) = filter.gt("balance", SqlU256::from(0x0fu64)).unwrap();
+        let count: i64 = sqlx::query_scalar(&format!("SELECT COUNT(*) FROM t WHERE {}", sql))
+            .bind(bind)
+            .fetch_one(&pool)
+            .await
+            .unwrap();
+        assert_eq!(count, 3);
+
+        let (sql, (low, high)) = filter
+            .between("balance", SqlU256::from(0x10u64), SqlU256::from(0xffu64))
+            .unwrap();
+        let count: i64 = sqlx::query_scalar(&format!("SELECT COUNT(*) FROM t WHERE {}", sql))
+            .bind(low)
+            .bind(high)
+            .fetch_one(&pool)
+            .await
+            .unwrap();
+        assert_eq!(count, 2);
+    }
+
+    #[tokio::test]
+    async fn test_in_clause_binds_on_sqlite() {
+        let pool = sqlx::sqlite::SqlitePoolOptions::new()
+            .max_connections(1)
+            .connect("sqlite::memory:")
+            .await
+            .unwrap();
+        sqlx::query("CREATE TABLE t (balance VARCHAR(66) NOT NULL)")
+            .execute(&pool)
+            .await
+            .unwrap();
+        for v in [1u64, 2, 3, 4] {
+            sqlx::query("INSERT INTO t (balance) VALUES (?)")
+                .bind(SqlU256::from(v))
+                .execute(&pool)
+                .await
+                .unwrap();
+        }
+
+        let wanted = [SqlU256::from(2u64), SqlU256::from(4u64)];
+        let sql = format!(
+            "SELECT COUNT(*) FROM t WHERE balance IN {}",
+            SqlU256::in_clause_placeholders(wanted.len(), Dialect::Sqlite)
+        );
+        let mut query = sqlx::query_scalar::<_, i64>(&sql);
+        for v in wanted {
+            query = query.bind(v);
+        }
+        assert_eq!(query.fetch_one(&pool).await.unwrap(), 2);
+    }
+}
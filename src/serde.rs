@@ -0,0 +1,220 @@
+//! This module is only available when the `serde` feature is enabled.
+//! Helper modules for use with `#[serde(with = "...")]`.
+//!
+//! The wrapper types already implement `Serialize`/`Deserialize` directly; the modules
+//! here cover payloads that encode values in a non-standard shape.
+#![cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+
+/// Flexible (de)serialization for [`SqlU256`](crate::SqlU256).
+///
+/// Serializes like the default `SqlU256` implementation, and deserializes from either:
+/// - a string, decimal (`"42"`) or `0x`-prefixed hex (`"0x2a"`)
+/// - a 32-byte big-endian byte array (as a sequence of `u8` or a byte buffer)
+///
+/// # Examples
+///
+/// ```
+/// use ethereum_mysql::SqlU256;
+/// use serde::Deserialize;
+///
+/// #[derive(Deserialize)]
+/// struct Transfer {
+///     #[serde(with = "ethereum_mysql::serde::u256_flexible")]
+///     value: SqlU256,
+/// }
+///
+/// let t: Transfer = serde_json::from_str(r#"{"value":"42"}"#).unwrap();
+/// assert_eq!(t.value, SqlU256::from(42u64));
+/// ```
+pub mod u256_flexible {
+    use crate::{SqlU256, U256};
+    use ::serde::de::{self, SeqAccess, Visitor};
+    use ::serde::{Deserializer, Serialize, Serializer};
+    use std::fmt;
+    use std::str::FromStr;
+
+    /// Serializes a `SqlU256` using its default representation.
+    pub fn serialize<S>(value: &SqlU256, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        value.serialize(serializer)
+    }
+
+    /// Deserializes a `SqlU256` from a string or a 32-byte big-endian array.
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<SqlU256, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_any(FlexibleVisitor)
+    }
+
+    struct FlexibleVisitor;
+
+    impl<'de> Visitor<'de> for FlexibleVisitor {
+        type Value = SqlU256;
+
+        fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            f.write_str("a decimal/hex string or a 32-byte array")
+        }
+
+        fn visit_str<E: de::Error>(self, v: &str) -> Result<SqlU256, E> {
+            SqlU256::from_str(v).map_err(E::custom)
+        }
+
+        fn visit_bytes<E: de::Error>(self, v: &[u8]) -> Result<SqlU256, E> {
+            let bytes: [u8; 32] = v
+                .try_into()
+                .map_err(|_| E::invalid_length(v.len(), &self))?;
+            Ok(SqlU256::from(U256::from_be_bytes(bytes)))
+        }
+
+        fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<SqlU256, A::Error> {
+            let mut bytes = [0u8; 32];
+            for (i, byte) in bytes.iter_mut().enumerate() {
+                *byte = seq
+                    .next_element()?
+                    .ok_or_else(|| de::Error::invalid_length(i, &self))?;
+            }
+            if seq.next_element::<u8>()?.is_some() {
+                return Err(de::Error::invalid_length(33, &self));
+            }
+            Ok(SqlU256::from(U256::from_be_bytes(bytes)))
+        }
+    }
+}
+
+/// Minimal hex (de)serialization for [`SqlU256`](crate::SqlU256), in the JSON-RPC `QUANTITY`
+/// encoding (the same output as ethers v6 `toQuantity`).
+///
+/// Always serializes as a `0x`-prefixed hex string without leading zeros (`"0x0"` for zero),
+/// regardless of the serializer's format. Deserializes from a decimal or `0x`-prefixed hex
+/// string, or from an unsigned integer.
+///
+/// # Examples
+///
+/// ```
+/// use ethereum_mysql::SqlU256;
+/// use serde::{Deserialize, Serialize};
+///
+/// #[derive(Deserialize, Serialize)]
+/// struct Tx {
+///     #[serde(with = "ethereum_mysql::serde::u256_minimal_hex")]
+///     value: SqlU256,
+/// }
+///
+/// let json = serde_json::to_string(&Tx { value: SqlU256::from(42u64) }).unwrap();
+/// assert_eq!(json, r#"{"value":"0x2a"}"#);
+/// ```
+pub mod u256_minimal_hex {
+    use crate::SqlU256;
+    use ::serde::de::{self, Visitor};
+    use ::serde::{Deserializer, Serializer};
+    use std::fmt;
+    use std::str::FromStr;
+
+    /// Serializes a `SqlU256` as minimal `0x`-prefixed hex.
+    pub fn serialize<S>(value: &SqlU256, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(&format!("{:#x}", value.inner()))
+    }
+
+    /// Deserializes a `SqlU256` from a decimal or hex string, or an unsigned integer.
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<SqlU256, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_any(MinimalHexVisitor)
+    }
+
+    struct MinimalHexVisitor;
+
+    impl<'de> Visitor<'de> for MinimalHexVisitor {
+        type Value = SqlU256;
+
+        fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            f.write_str("a decimal/hex string or an unsigned integer")
+        }
+
+        fn visit_str<E: de::Error>(self, v: &str) -> Result<SqlU256, E> {
+            SqlU256::from_str(v).map_err(E::custom)
+        }
+
+        fn visit_u64<E: de::Error>(self, v: u64) -> Result<SqlU256, E> {
+            Ok(SqlU256::from(v))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::SqlU256;
+    use serde::{Deserialize, Serialize};
+
+    #[derive(Debug, Deserialize, Serialize)]
+    struct Payload {
+        #[serde(with = "crate::serde::u256_flexible")]
+        value: SqlU256,
+    }
+
+    #[test]
+    fn test_u256_flexible() {
+        let expected = SqlU256::from(42u64);
+
+        let hex: Payload = serde_json::from_str(r#"{"value":"0x2a"}"#).unwrap();
+        let dec: Payload = serde_json::from_str(r#"{"value":"42"}"#).unwrap();
+        let mut arr = [0u8; 32];
+        arr[31] = 42;
+        let json = format!(r#"{{"value":{}}}"#, serde_json::to_string(&arr).unwrap());
+        let bytes: Payload = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(hex.value, expected);
+        assert_eq!(dec.value, expected);
+        assert_eq!(bytes.value, expected);
+
+        // Serialization keeps the default representation and round-trips.
+        let out = serde_json::to_string(&dec).unwrap();
+        let back: Payload = serde_json::from_str(&out).unwrap();
+        assert_eq!(back.value, expected);
+
+        assert!(serde_json::from_str::<Payload>(r#"{"value":[1,2,3]}"#).is_err());
+        assert!(serde_json::from_str::<Payload>(r#"{"value":"-1"}"#).is_err());
+    }
+
+    #[derive(Debug, Deserialize, Serialize)]
+    struct MinimalPayload {
+        #[serde(with = "crate::serde::u256_minimal_hex")]
+        value: SqlU256,
+    }
+
+    #[test]
+    fn test_u256_minimal_hex() {
+        // JSON-RPC QUANTITY: no leading zeros, not padded to whole bytes (ethers v6 toQuantity)
+        let zero = MinimalPayload {
+            value: SqlU256::ZERO,
+        };
+        assert_eq!(serde_json::to_string(&zero).unwrap(), r#"{"value":"0x0"}"#);
+        let mid = MinimalPayload {
+            value: SqlU256::from(1_000_000u64),
+        };
+        assert_eq!(
+            serde_json::to_string(&mid).unwrap(),
+            r#"{"value":"0xf4240"}"#
+        );
+
+        for json in [
+            r#"{"value":"0xf4240"}"#,
+            r#"{"value":"0x00000f4240"}"#,
+            r#"{"value":"1000000"}"#,
+            r#"{"value":1000000}"#,
+        ] {
+            let p: MinimalPayload = serde_json::from_str(json).unwrap();
+            assert_eq!(p.value, SqlU256::from(1_000_000u64));
+        }
+
+        assert!(serde_json::from_str::<MinimalPayload>(r#"{"value":"0xzz"}"#).is_err());
+        assert!(serde_json::from_str::<MinimalPayload>(r#"{"value":-1}"#).is_err());
+    }
+}
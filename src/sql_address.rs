@@ -1,7 +1,12 @@
+#[cfg(feature = "signer")]
+use crate::SqlSignature;
+use crate::{SqlBloom, SqlFixedBytes, SqlHash, SqlU256};
 pub use alloy::primitives::Address;
+#[cfg(feature = "signer")]
+use alloy::primitives::SignatureError;
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
-use std::ops::Deref;
+use std::ops::{Deref, Range};
 use std::str::FromStr;
 
 /// SQL-compatible wrapper for Ethereum Address.
@@ -84,14 +89,251 @@ impl SqlAddress {
         self.0
     }
 
+    /// Returns true if this is the zero address (`0x0000…0000`).
+    pub fn is_zero(&self) -> bool {
+        self.0.is_zero()
+    }
+
+    /// Builds an EIP-681 payment URI, e.g. `ethereum:0x…@1?value=1000`.
+    ///
+    /// The address is EIP-55 checksummed, `chain_id` is always included, and `value`
+    /// (in wei) is written as a decimal `value` parameter when given.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ethereum_mysql::{sqladdress, SqlU256};
+    ///
+    /// let addr = sqladdress!("0xfb6916095ca1df60bb79ce92ce3ea74c37c5d359");
+    /// assert_eq!(
+    ///     addr.to_eip681_uri(1, Some(SqlU256::from(2_014_000_000_000_000_000u64))),
+    ///     "ethereum:0xfB6916095ca1df60bB79Ce92cE3Ea74c37c5d359@1?value=2014000000000000000"
+    /// );
+    /// ```
+    pub fn to_eip681_uri(&self, chain_id: u64, value: Option<SqlU256>) -> String {
+        let mut uri = format!("ethereum:{}@{}", self.to_checksum_string(), chain_id);
+        if let Some(value) = value {
+            uri.push_str(&format!("?value={}", value.inner()));
+        }
+        uri
+    }
+
+    /// Returns the 20 address bytes as a `SqlFixedBytes<20>`.
+    pub fn to_fixed_bytes(&self) -> SqlFixedBytes<20> {
+        SqlFixedBytes::from(self.0 .0)
+    }
+
     /// Creates a SqlAddress from a byte slice (must be 20 bytes).
     ///
     /// # Panics
     ///
-    /// Panics if the slice is not exactly 20 bytes.
+    /// Panics if the slice is not exactly 20 bytes. Use `SqlAddress::try_from` for
+    /// untrusted input.
     pub fn from_slice(bytes: &[u8]) -> Self {
         SqlAddress(Address::from_slice(bytes))
     }
+
+    /// Returns the address exactly as it is written to the database:
+    /// lowercase hex with a `0x` prefix.
+    ///
+    /// `Display` uses the EIP-55 checksum format, so use this method when logs
+    /// should match the stored column value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ethereum_mysql::sqladdress;
+    ///
+    /// let addr = sqladdress!("0x742d35Cc6635C0532925a3b8D42cC72b5c2A9A1d");
+    /// assert_eq!(addr.to_storage_string(), "0x742d35cc6635c0532925a3b8d42cc72b5c2a9a1d");
+    /// ```
+    pub fn to_storage_string(&self) -> String {
+        format!("{:#x}", self.0)
+    }
+
+    /// Returns the EIP-55 checksummed form, the same string `Display` produces.
+    pub fn to_checksum_string(&self) -> String {
+        self.0.to_checksum(None)
+    }
+
+    /// Returns the all-lowercase `0x`-prefixed form, e.g. for systems that reject
+    /// mixed-case addresses. Same as [`to_storage_string`](Self::to_storage_string).
+    pub fn to_lowercase_string(&self) -> String {
+        self.to_storage_string()
+    }
+
+    /// Returns the EIP-1191 checksummed form, which salts the checksum with `chain_id`.
+    ///
+    /// Only chains that adopted EIP-1191 (e.g. RSK, chain ID 30) expect this form;
+    /// for Ethereum mainnet use [`to_checksum_string`](Self::to_checksum_string).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ethereum_mysql::sqladdress;
+    ///
+    /// let addr = sqladdress!("0x5aaeb6053f3e94c9b9a09f33669435e7ef1beaed");
+    /// assert_eq!(
+    ///     addr.to_checksum_with_chain_id(30),
+    ///     "0x5aaEB6053f3e94c9b9a09f33669435E7ef1bEAeD"
+    /// );
+    /// ```
+    pub fn to_checksum_with_chain_id(&self, chain_id: u64) -> String {
+        self.0.to_checksum(Some(chain_id))
+    }
+
+    /// Writes the address as 40 lowercase hex ASCII characters (no `0x` prefix)
+    /// into a fixed array, without allocating.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ethereum_mysql::sqladdress;
+    ///
+    /// let addr = sqladdress!("0x742d35Cc6635C0532925a3b8D42cC72b5c2A9A1d");
+    /// let chars = addr.to_hex_chars();
+    /// assert_eq!(&chars, b"742d35cc6635c0532925a3b8d42cc72b5c2a9a1d");
+    /// ```
+    pub fn to_hex_chars(&self) -> [u8; 40] {
+        const HEX: &[u8; 16] = b"0123456789abcdef";
+        let mut out = [0u8; 40];
+        for (i, byte) in self.0.iter().enumerate() {
+            out[i * 2] = HEX[(byte >> 4) as usize];
+            out[i * 2 + 1] = HEX[(byte & 0x0f) as usize];
+        }
+        out
+    }
+
+    /// Formats the address as a CSV field, lowercase to match the stored column value.
+    pub fn to_csv_field(&self) -> String {
+        self.to_storage_string()
+    }
+
+    /// Parses a CSV line of addresses.
+    ///
+    /// Fields are split on commas; surrounding whitespace and double/single quotes are
+    /// trimmed from each field. An empty line yields an empty `Vec`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ethereum_mysql::SqlAddress;
+    ///
+    /// let line = r#""0x0000000000000000000000000000000000000000", 0x0000000000000000000000000000000000000001"#;
+    /// let addrs = SqlAddress::parse_csv_line(line).unwrap();
+    /// assert_eq!(addrs.len(), 2);
+    /// assert_eq!(addrs[0], SqlAddress::ZERO);
+    /// ```
+    pub fn parse_csv_line(line: &str) -> Result<Vec<SqlAddress>, <Address as FromStr>::Err> {
+        if line.trim().is_empty() {
+            return Ok(Vec::new());
+        }
+        line.split(',')
+            .map(|field| SqlAddress::from_str(field.trim().trim_matches(|c| c == '"' || c == '\'')))
+            .collect()
+    }
+
+    /// Computes the address of a contract deployed by `deployer` with `CREATE`.
+    ///
+    /// The address is `keccak256(rlp([deployer, nonce]))[12..]`.
+    pub fn create(deployer: &SqlAddress, nonce: u64) -> SqlAddress {
+        SqlAddress(deployer.0.create(nonce))
+    }
+
+    /// Computes the address of a contract deployed by `deployer` with `CREATE2`.
+    ///
+    /// The address is `keccak256(0xff ++ deployer ++ salt ++ init_code_hash)[12..]`, so
+    /// factory-created contracts (e.g. Uniswap V2 pairs) can be derived without an RPC call.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ethereum_mysql::{sqladdress, SqlAddress, SqlHash};
+    ///
+    /// // EIP-1014 example 0
+    /// let addr = SqlAddress::create2(&SqlAddress::ZERO, &SqlHash::ZERO, &SqlHash::keccak256([0u8]));
+    /// assert_eq!(addr, sqladdress!("0x4D1A2e2bB4F88F0250f26Ffff098B0b30B26BF38"));
+    /// ```
+    pub fn create2(deployer: &SqlAddress, salt: &SqlHash, init_code_hash: &SqlHash) -> SqlAddress {
+        SqlAddress(deployer.0.create2(salt.inner(), init_code_hash.inner()))
+    }
+
+    /// Computes the `CREATE` addresses of `deployer` for every nonce in `nonces`.
+    ///
+    /// Useful for indexing the contracts an account is going to deploy.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ethereum_mysql::{sqladdress, SqlAddress};
+    ///
+    /// let deployer = sqladdress!("0x6ac7ea33f8831ea9dcc53393aaa88b25a785dbf0");
+    /// let addrs = SqlAddress::contract_addresses(deployer, 0..2);
+    /// assert_eq!(addrs[0], sqladdress!("0xcd234a471b72ba2f1ccf0a70fcaba648a5eecd8d"));
+    /// assert_eq!(addrs.len(), 2);
+    /// ```
+    pub fn contract_addresses(deployer: SqlAddress, nonces: Range<u64>) -> Vec<SqlAddress> {
+        nonces
+            .map(|nonce| SqlAddress::create(&deployer, nonce))
+            .collect()
+    }
+
+    /// Recovers the address that produced `signature` over `message_hash` (ecrecover).
+    ///
+    /// `message_hash` is the already-hashed message, e.g. the EIP-191 hash for `eth_sign`.
+    /// Returns Err if `v` is not a valid recovery byte or the signature is invalid.
+    #[cfg(feature = "signer")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "signer")))]
+    pub fn recover_signer(
+        message_hash: &SqlHash,
+        signature: &SqlSignature,
+    ) -> Result<SqlAddress, SignatureError> {
+        signature
+            .to_signature()?
+            .recover_address_from_prehash(message_hash.inner())
+            .map(SqlAddress)
+    }
+
+    /// Normalizes an address string to the lowercase storage form for sorting.
+    ///
+    /// Checksummed (mixed-case) values do not sort in byte order, so a column written by
+    /// legacy code can be migrated with this before relying on `ORDER BY`. Lowercase
+    /// `0x`-prefixed hex sorts identically to the address bytes under a binary collation
+    /// (e.g. `ascii_bin` in MySQL, `COLLATE "C"` in PostgreSQL; SQLite's default `BINARY`).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ethereum_mysql::SqlAddress;
+    ///
+    /// let normalized =
+    ///     SqlAddress::normalize_for_sort("0x742d35Cc6635C0532925a3b8D42cC72b5c2A9A1d").unwrap();
+    /// assert_eq!(normalized, "0x742d35cc6635c0532925a3b8d42cc72b5c2a9a1d");
+    /// ```
+    pub fn normalize_for_sort(s: &str) -> Result<String, <Address as FromStr>::Err> {
+        SqlAddress::from_str(s).map(|addr| addr.to_storage_string())
+    }
+
+    /// Verifies that this address is a leaf of the Merkle tree with the given `root`.
+    ///
+    /// Leaves are `keccak256(address)` (i.e. `keccak256(abi.encodePacked(account))`) and
+    /// nodes are combined with [`SqlHash::hash_pair_sorted`], following OpenZeppelin's
+    /// `MerkleProof.verify`.
+    pub fn verify_merkle_proof(&self, proof: &[SqlHash], root: &SqlHash) -> bool {
+        let leaf = SqlHash::from_preimage(self.0.as_slice());
+        let computed = proof.iter().fold(leaf, |node, sibling| {
+            SqlHash::hash_pair_sorted(&node, sibling)
+        });
+        computed == *root
+    }
+
+    /// Checks whether this address might be present in the given logs bloom.
+    ///
+    /// Uses the standard Ethereum 3-index bloom algorithm on `keccak256(address)`.
+    /// A `false` result is definitive, while `true` may be a false positive.
+    pub fn maybe_in_bloom(&self, bloom: &SqlBloom) -> bool {
+        bloom.contains_address(self)
+    }
 }
 
 impl AsRef<Address> for SqlAddress {
@@ -127,6 +369,41 @@ impl From<SqlAddress> for Address {
     }
 }
 
+#[cfg(feature = "fixed-hash-compat")]
+#[cfg_attr(docsrs, doc(cfg(feature = "fixed-hash-compat")))]
+impl From<primitive_types::H160> for SqlAddress {
+    /// Creates a SqlAddress from a `fixed-hash` based H160 (same byte order).
+    fn from(hash: primitive_types::H160) -> Self {
+        SqlAddress::new(hash.0)
+    }
+}
+
+#[cfg(feature = "fixed-hash-compat")]
+#[cfg_attr(docsrs, doc(cfg(feature = "fixed-hash-compat")))]
+impl From<SqlAddress> for primitive_types::H160 {
+    /// Converts a SqlAddress into a `fixed-hash` based H160 (same byte order).
+    fn from(sql_address: SqlAddress) -> Self {
+        primitive_types::H160(sql_address.0 .0 .0)
+    }
+}
+
+impl TryFrom<&[u8]> for SqlAddress {
+    type Error = &'static str;
+
+    /// Non-panicking alternative to [`SqlAddress::from_slice`] for untrusted input.
+    fn try_from(bytes: &[u8]) -> Result<Self, Self::Error> {
+        <[u8; 20]>::try_from(bytes)
+            .map(SqlAddress::new)
+            .map_err(|_| "Byte slice must be exactly 20 bytes to convert to SqlAddress")
+    }
+}
+
+impl From<SqlFixedBytes<20>> for SqlAddress {
+    fn from(bytes: SqlFixedBytes<20>) -> Self {
+        SqlAddress(Address::from(*bytes.inner()))
+    }
+}
+
 impl FromStr for SqlAddress {
     type Err = <Address as FromStr>::Err;
 
@@ -136,8 +413,10 @@ impl FromStr for SqlAddress {
     /// - With 0x prefix: "0x742d35Cc6635C0532925a3b8D42cC72b5c2A9A1d"
     /// - Without prefix: "742d35Cc6635C0532925a3b8D42cC72b5c2A9A1d"
     /// - Mixed case (checksummed) and lowercase formats
+    ///
+    /// Leading and trailing whitespace is ignored; whitespace inside the address is rejected.
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        Ok(SqlAddress(s.parse()?))
+        Ok(SqlAddress(s.trim().parse()?))
     }
 }
 
@@ -199,6 +478,97 @@ mod tests {
         assert_eq!(displayed.to_lowercase(), TEST_ADDRESS_STR.to_lowercase());
     }
 
+    #[test]
+    fn test_try_from_slice() {
+        let addr = sqladdress!("0x742d35Cc6635C0532925a3b8D42cC72b5c2A9A1d");
+        let bytes = addr.as_slice();
+        assert_eq!(SqlAddress::try_from(bytes).unwrap(), addr);
+        assert!(SqlAddress::try_from(&bytes[..19]).is_err());
+        let long = [bytes, &[0u8]].concat();
+        assert!(SqlAddress::try_from(&long[..]).is_err());
+        assert!(SqlAddress::try_from(&[][..]).is_err());
+    }
+
+    #[test]
+    fn test_eip681_uri() {
+        let addr = sqladdress!("0xfb6916095ca1df60bb79ce92ce3ea74c37c5d359");
+        assert_eq!(
+            addr.to_eip681_uri(1, None),
+            "ethereum:0xfB6916095ca1df60bB79Ce92cE3Ea74c37c5d359@1"
+        );
+        assert_eq!(
+            addr.to_eip681_uri(137, Some(SqlU256::from(10u64).pow(18))),
+            "ethereum:0xfB6916095ca1df60bB79Ce92cE3Ea74c37c5d359@137?value=1000000000000000000"
+        );
+        assert!(addr
+            .to_eip681_uri(1, Some(SqlU256::ZERO))
+            .ends_with("?value=0"));
+    }
+
+    #[test]
+    fn test_is_zero() {
+        assert!(SqlAddress::ZERO.is_zero());
+        assert!(SqlAddress::default().is_zero());
+        let addr = sqladdress!("0x742d35Cc6635C0532925a3b8D42cC72b5c2A9A1d");
+        assert!(!addr.is_zero());
+        assert!(!sqladdress!("0x0000000000000000000000000000000000000001").is_zero());
+    }
+
+    #[test]
+    fn test_to_fixed_bytes() {
+        let addr = sqladdress!("0x742d35Cc6635C0532925a3b8D42cC72b5c2A9A1d");
+        let bytes = addr.to_fixed_bytes();
+        assert_eq!(bytes.as_slice(), addr.as_slice());
+        assert_eq!(
+            bytes.to_string(),
+            "0x742d35cc6635c0532925a3b8d42cc72b5c2a9a1d"
+        );
+        assert_eq!(SqlAddress::from(bytes), addr);
+        assert_eq!(SqlAddress::ZERO.to_fixed_bytes(), SqlFixedBytes::<20>::ZERO);
+    }
+
+    #[test]
+    fn test_checksum_strings() {
+        let addr = sqladdress!("0x5aaeb6053f3e94c9b9a09f33669435e7ef1beaed");
+        assert_eq!(
+            addr.to_checksum_string(),
+            "0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed"
+        );
+        assert_eq!(addr.to_checksum_string(), addr.to_string());
+        assert_eq!(
+            addr.to_lowercase_string(),
+            "0x5aaeb6053f3e94c9b9a09f33669435e7ef1beaed"
+        );
+
+        // EIP-1191 test vectors for RSK mainnet (30) and testnet (31)
+        assert_eq!(
+            addr.to_checksum_with_chain_id(30),
+            "0x5aaEB6053f3e94c9b9a09f33669435E7ef1bEAeD"
+        );
+        assert_eq!(
+            addr.to_checksum_with_chain_id(31),
+            "0x5aAeb6053F3e94c9b9A09F33669435E7EF1BEaEd"
+        );
+        let other = sqladdress!("0xfb6916095ca1df60bb79ce92ce3ea74c37c5d359");
+        assert_eq!(
+            other.to_checksum_with_chain_id(30),
+            "0xFb6916095cA1Df60bb79ce92cE3EA74c37c5d359"
+        );
+    }
+
+    #[test]
+    fn test_to_hex_chars() {
+        for addr in [
+            sqladdress!("0x742d35Cc6635C0532925a3b8D42cC72b5c2A9A1d"),
+            sqladdress!("0xFFfFfFffFFfffFFfFFfFFFFFffFFFffffFfFFFfF"),
+            SqlAddress::ZERO,
+        ] {
+            let chars = addr.to_hex_chars();
+            let lower = addr.to_string().to_lowercase();
+            assert_eq!(std::str::from_utf8(&chars).unwrap(), &lower[2..]);
+        }
+    }
+
     #[test]
     fn test_sql_address_deref() {
         let sql_addr = SqlAddress::from_str(TEST_ADDRESS_STR).unwrap();
@@ -242,6 +612,23 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_from_str_trims_whitespace() {
+        let expected = SqlAddress::from_str(TEST_ADDRESS_STR).unwrap();
+        assert_eq!(
+            SqlAddress::from_str(&format!("  {}  ", TEST_ADDRESS_STR)).unwrap(),
+            expected
+        );
+        assert_eq!(
+            SqlAddress::from_str(&format!("\t{}\n", TEST_ADDRESS_STR)).unwrap(),
+            expected
+        );
+
+        assert!(SqlAddress::from_str("0x 742d35Cc6635C0532925a3b8D42cC72b5c2A9A1d").is_err());
+        assert!(SqlAddress::from_str("0x742d35Cc6635C0532925 a3b8D42cC72b5c2A9A1d").is_err());
+        assert!(SqlAddress::from_str("   ").is_err());
+    }
+
     #[cfg(feature = "serde")]
     #[test]
     fn test_sql_address_serde() {
@@ -457,4 +844,204 @@ mod tests {
             calculate_hash(&SqlAddress::ZERO)
         );
     }
+
+    #[test]
+    fn test_normalize_for_sort() {
+        let inputs = [
+            "0x742d35Cc6635C0532925a3b8D42cC72b5c2A9A1d",
+            "0xA0b86991c6218b36c1d19D4a2e9Eb0cE3606eB48",
+            "0x0000000000000000000000000000000000000001",
+        ];
+        let mut normalized: Vec<String> = inputs
+            .iter()
+            .map(|s| SqlAddress::normalize_for_sort(s).unwrap())
+            .collect();
+        assert_eq!(normalized[0], "0x742d35cc6635c0532925a3b8d42cc72b5c2a9a1d");
+        assert!(normalized.iter().all(|s| *s == s.to_lowercase()));
+
+        // String order of the normalized form matches byte order of the addresses.
+        let mut addrs: Vec<SqlAddress> = inputs
+            .iter()
+            .map(|s| SqlAddress::from_str(s).unwrap())
+            .collect();
+        normalized.sort();
+        addrs.sort();
+        let from_bytes: Vec<String> = addrs.iter().map(|a| a.to_storage_string()).collect();
+        assert_eq!(normalized, from_bytes);
+
+        assert!(SqlAddress::normalize_for_sort("0x1234").is_err());
+    }
+
+    #[test]
+    fn test_verify_merkle_proof() {
+        let accounts = [
+            sqladdress!("0x1111111111111111111111111111111111111111"),
+            sqladdress!("0x2222222222222222222222222222222222222222"),
+            sqladdress!("0x3333333333333333333333333333333333333333"),
+            sqladdress!("0x4444444444444444444444444444444444444444"),
+        ];
+        let leaves: Vec<SqlHash> = accounts
+            .iter()
+            .map(|a| SqlHash::from_preimage(a.as_slice()))
+            .collect();
+        let left = SqlHash::hash_pair_sorted(&leaves[0], &leaves[1]);
+        let right = SqlHash::hash_pair_sorted(&leaves[2], &leaves[3]);
+        let root = SqlHash::hash_pair_sorted(&left, &right);
+
+        assert!(accounts[0].verify_merkle_proof(&[leaves[1], right], &root));
+        assert!(accounts[3].verify_merkle_proof(&[leaves[2], left], &root));
+
+        let outsider = sqladdress!("0x5555555555555555555555555555555555555555");
+        assert!(!outsider.verify_merkle_proof(&[leaves[1], right], &root));
+        // A valid proof for another leaf does not verify this one.
+        assert!(!accounts[1].verify_merkle_proof(&[leaves[2], left], &root));
+    }
+
+    #[test]
+    fn test_csv_helpers() {
+        let line = r#" "0x742d35Cc6635C0532925a3b8D42cC72b5c2A9A1d" , '0x0000000000000000000000000000000000000001',0x0000000000000000000000000000000000000000 "#;
+        let addrs = SqlAddress::parse_csv_line(line).unwrap();
+        assert_eq!(
+            addrs,
+            vec![
+                sqladdress!("0x742d35Cc6635C0532925a3b8D42cC72b5c2A9A1d"),
+                sqladdress!("0x0000000000000000000000000000000000000001"),
+                SqlAddress::ZERO,
+            ]
+        );
+        assert_eq!(
+            addrs[0].to_csv_field(),
+            "0x742d35cc6635c0532925a3b8d42cc72b5c2a9a1d"
+        );
+
+        let joined = addrs
+            .iter()
+            .map(SqlAddress::to_csv_field)
+            .collect::<Vec<_>>()
+            .join(",");
+        assert_eq!(SqlAddress::parse_csv_line(&joined).unwrap(), addrs);
+
+        assert!(SqlAddress::parse_csv_line("").unwrap().is_empty());
+        assert!(SqlAddress::parse_csv_line("0x1234,").is_err());
+    }
+
+    #[test]
+    fn test_create() {
+        let deployer = sqladdress!("0x6ac7ea33f8831ea9dcc53393aaa88b25a785dbf0");
+        assert_eq!(
+            SqlAddress::create(&deployer, 0),
+            sqladdress!("0xcd234a471b72ba2f1ccf0a70fcaba648a5eecd8d")
+        );
+        assert_eq!(
+            SqlAddress::create(&deployer, 2),
+            sqladdress!("0xf778b86fa74e846c4f0a1fbd1335fe81c00a0c91")
+        );
+    }
+
+    #[test]
+    fn test_create2_uniswap_v2_pair() {
+        // UniswapV2Factory.createPair(USDC, WETH)
+        let factory = sqladdress!("0x5C69bEe701ef814a2B6a3EDD4B1652CB9cc5aA6f");
+        let init_code_hash =
+            SqlHash::from_str("0x96e8ac4277198ff8b6f785478aa9a39f403cb768dd02cbee326c3e7da348845f")
+                .unwrap();
+        let usdc = sqladdress!("0xA0b86991c6218b36c1d19D4a2e9Eb0cE3606eB48");
+        let weth = sqladdress!("0xC02aaA39b223FE8D0A0e5C4F27eAD9083C756Cc2");
+        // salt = keccak256(abi.encodePacked(token0, token1)) with token0 < token1
+        let salt = SqlHash::keccak256([usdc.as_slice(), weth.as_slice()].concat());
+
+        assert_eq!(
+            SqlAddress::create2(&factory, &salt, &init_code_hash),
+            sqladdress!("0xB4e16d0168e52d35CaCD2c6185b44281Ec28C9Dc")
+        );
+    }
+
+    #[test]
+    fn test_contract_addresses() {
+        let deployer = sqladdress!("0x6ac7ea33f8831ea9dcc53393aaa88b25a785dbf0");
+        let addrs = SqlAddress::contract_addresses(deployer, 0..3);
+        assert_eq!(
+            addrs,
+            vec![
+                sqladdress!("0xcd234a471b72ba2f1ccf0a70fcaba648a5eecd8d"),
+                sqladdress!("0x343c43a37d37dff08ae8c4a11544c718abb4fcf8"),
+                sqladdress!("0xf778b86fa74e846c4f0a1fbd1335fe81c00a0c91"),
+            ]
+        );
+        assert_eq!(addrs[1], SqlAddress::create(&deployer, 1));
+        assert!(SqlAddress::contract_addresses(deployer, 5..5).is_empty());
+    }
+
+    #[cfg(feature = "signer")]
+    #[test]
+    fn test_recover_signer() {
+        use crate::{SqlHash, SqlSignature};
+        use alloy::primitives::keccak256;
+        use k256::ecdsa::SigningKey;
+
+        // Private key 0x...01 controls this well-known address.
+        let mut secret = [0u8; 32];
+        secret[31] = 1;
+        let key = SigningKey::from_bytes(&secret.into()).unwrap();
+        let expected = sqladdress!("0x7E5F4552091A69125d5DfCb7b8C2659029395Bdf");
+
+        let hash = SqlHash::from_bytes(keccak256(b"hello ethereum-mysql"));
+        let (sig, recid) = key.sign_prehash_recoverable(hash.as_slice()).unwrap();
+        let mut raw = [0u8; 65];
+        raw[..64].copy_from_slice(&sig.to_bytes());
+        raw[64] = 27 + recid.to_byte();
+        let signature = SqlSignature::new(raw);
+
+        assert_eq!(
+            SqlAddress::recover_signer(&hash, &signature).unwrap(),
+            expected
+        );
+
+        let other = SqlHash::from_bytes(keccak256(b"tampered"));
+        assert_ne!(
+            SqlAddress::recover_signer(&other, &signature).ok(),
+            Some(expected)
+        );
+
+        raw[64] = 5;
+        assert!(SqlAddress::recover_signer(&hash, &SqlSignature::new(raw)).is_err());
+    }
+
+    #[cfg(feature = "fixed-hash-compat")]
+    #[test]
+    fn test_h160_round_trip() {
+        use primitive_types::H160;
+
+        let addr = sqladdress!("0x742d35Cc6635C0532925a3b8D42cC72b5c2A9A1d");
+        let h160 = H160::from(addr);
+        assert_eq!(h160.as_bytes(), addr.as_slice());
+        assert_eq!(SqlAddress::from(h160), addr);
+
+        assert_eq!(H160::from(SqlAddress::ZERO), H160::zero());
+        assert_eq!(SqlAddress::from(H160::zero()), SqlAddress::ZERO);
+
+        let mut raw = [0u8; 20];
+        raw[18] = 0xde;
+        raw[19] = 0xad;
+        assert_eq!(
+            SqlAddress::from(H160(raw)),
+            sqladdress!("0x000000000000000000000000000000000000dead")
+        );
+    }
+
+    #[test]
+    fn test_maybe_in_bloom() {
+        use alloy::primitives::{Bloom, BloomInput};
+
+        let addr = sqladdress!("0x742d35Cc6635C0532925a3b8D42cC72b5c2A9A1d");
+        let other = sqladdress!("0x1234567890123456789012345678901234567890");
+
+        let mut bloom = Bloom::ZERO;
+        bloom.accrue(BloomInput::Raw(addr.as_slice()));
+        let sql_bloom = SqlBloom::from(bloom);
+
+        assert!(addr.maybe_in_bloom(&sql_bloom));
+        assert!(!other.maybe_in_bloom(&sql_bloom));
+        assert!(!addr.maybe_in_bloom(&SqlBloom::ZERO));
+    }
 }
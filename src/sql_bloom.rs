@@ -0,0 +1,192 @@
+use crate::{SqlAddress, SqlFixedBytes, SqlHash};
+use alloy::primitives::{Bloom, BloomInput};
+use std::ops::Deref;
+use std::str::FromStr;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// A SQL-compatible wrapper for 2048-bit (256-byte) logs blooms.
+///
+/// `SqlBloom` wraps `alloy::primitives::Bloom` as found in block headers and receipts.
+/// `Display` and the SQLx encoding produce a `0x`-prefixed, 514-character lowercase hex
+/// string. Membership checks use the standard 3-index bloom algorithm, so a `false`
+/// result is definitive while `true` may be a false positive; use them to decide which
+/// blocks need a full log scan.
+///
+/// # Examples
+///
+/// ```rust
+/// use ethereum_mysql::{sqladdress, SqlBloom};
+///
+/// let token = sqladdress!("0xdAC17F958D2ee523a2206206994597C13D831ec7");
+/// let mut bloom = SqlBloom::ZERO;
+/// bloom.accrue_address(&token);
+/// assert!(bloom.contains_address(&token));
+/// assert_eq!(bloom.to_string().len(), 514);
+/// ```
+#[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct SqlBloom(Bloom);
+
+impl SqlBloom {
+    /// Empty bloom with no bits set.
+    pub const ZERO: Self = SqlBloom(Bloom::ZERO);
+
+    /// Creates a new `SqlBloom` from raw bytes.
+    pub fn new(bytes: [u8; 256]) -> Self {
+        SqlBloom(Bloom::new(bytes))
+    }
+
+    /// Returns a reference to the inner `Bloom`.
+    pub fn inner(&self) -> &Bloom {
+        &self.0
+    }
+
+    /// Returns true if `address` may have emitted a log covered by this bloom.
+    pub fn contains_address(&self, address: &SqlAddress) -> bool {
+        self.0.contains_input(BloomInput::Raw(address.as_slice()))
+    }
+
+    /// Returns true if `topic` may appear in a log covered by this bloom.
+    pub fn contains_topic(&self, topic: &SqlHash) -> bool {
+        self.0.contains_input(BloomInput::Raw(topic.as_slice()))
+    }
+
+    /// Adds `address` to the bloom.
+    pub fn accrue_address(&mut self, address: &SqlAddress) {
+        self.0.accrue(BloomInput::Raw(address.as_slice()));
+    }
+
+    /// Adds `topic` to the bloom.
+    pub fn accrue_topic(&mut self, topic: &SqlHash) {
+        self.0.accrue(BloomInput::Raw(topic.as_slice()));
+    }
+
+    /// Returns true if no bits are set, i.e. the bloom covers no logs.
+    pub fn is_zero(&self) -> bool {
+        self.0.is_zero()
+    }
+}
+
+impl Deref for SqlBloom {
+    type Target = Bloom;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl AsRef<[u8]> for SqlBloom {
+    fn as_ref(&self) -> &[u8] {
+        self.0.as_slice()
+    }
+}
+
+impl From<Bloom> for SqlBloom {
+    fn from(bloom: Bloom) -> Self {
+        SqlBloom(bloom)
+    }
+}
+
+impl From<SqlBloom> for Bloom {
+    fn from(bloom: SqlBloom) -> Self {
+        bloom.0
+    }
+}
+
+impl From<[u8; 256]> for SqlBloom {
+    fn from(bytes: [u8; 256]) -> Self {
+        SqlBloom::new(bytes)
+    }
+}
+
+impl From<SqlFixedBytes<256>> for SqlBloom {
+    fn from(bytes: SqlFixedBytes<256>) -> Self {
+        SqlBloom(Bloom::from(*bytes.inner()))
+    }
+}
+
+impl FromStr for SqlBloom {
+    type Err = <Bloom as FromStr>::Err;
+
+    /// Parses 512 hex digits, with or without a `0x` prefix.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Bloom::from_str(s).map(SqlBloom)
+    }
+}
+
+impl std::fmt::Display for SqlBloom {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.0 .0.fmt(f)
+    }
+}
+
+impl Default for SqlBloom {
+    fn default() -> Self {
+        Self::ZERO
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sqladdress;
+
+    fn transfer_bloom() -> (SqlBloom, SqlAddress, [SqlHash; 3]) {
+        let token = sqladdress!("0xdAC17F958D2ee523a2206206994597C13D831ec7");
+        let topics = [
+            // Transfer(address,address,uint256)
+            SqlHash::from_str("0xddf252ad1be2c89b69c2b068fc378daa952ba7f163c4a11628f55a4df523b3ef")
+                .unwrap(),
+            SqlHash::from_u256(crate::SqlU256::from(0x1111u64)),
+            SqlHash::from_u256(crate::SqlU256::from(0x2222u64)),
+        ];
+        let mut bloom = SqlBloom::ZERO;
+        bloom.accrue_address(&token);
+        for topic in &topics {
+            bloom.accrue_topic(topic);
+        }
+        (bloom, token, topics)
+    }
+
+    #[test]
+    fn test_membership() {
+        let (bloom, token, topics) = transfer_bloom();
+        assert!(bloom.contains_address(&token));
+        for topic in &topics {
+            assert!(bloom.contains_topic(topic));
+        }
+
+        let other = sqladdress!("0x742d35Cc6635C0532925a3b8D42cC72b5c2A9A1d");
+        assert!(!bloom.contains_address(&other));
+        // Approval(address,address,uint256)
+        let approval =
+            SqlHash::from_str("0x8c5be1e5ebec7d5bd14f71427d1e84f3dd0314c0f7b2291e5b200ac8c7c3b925")
+                .unwrap();
+        assert!(!bloom.contains_topic(&approval));
+
+        assert!(SqlBloom::ZERO.is_zero());
+        assert!(!bloom.is_zero());
+        assert!(!SqlBloom::ZERO.contains_address(&token));
+    }
+
+    #[test]
+    fn test_matches_alloy_accrue() {
+        let (bloom, token, topics) = transfer_bloom();
+        let mut expected = Bloom::ZERO;
+        expected.accrue_raw_log(*token.inner(), &topics.map(|topic| *topic.inner()));
+        assert_eq!(*bloom.inner(), expected);
+    }
+
+    #[test]
+    fn test_hex_round_trip() {
+        let (bloom, _, _) = transfer_bloom();
+        let s = bloom.to_string();
+        assert_eq!(s.len(), 514);
+        assert!(s.starts_with("0x"));
+        assert_eq!(SqlBloom::from_str(&s).unwrap(), bloom);
+        assert_eq!(SqlBloom::from_str(&s[2..]).unwrap(), bloom);
+        assert!(SqlBloom::from_str(&s[..512]).is_err());
+    }
+}
@@ -1,7 +1,9 @@
+use crate::{SqlAddress, SqlFixedBytes, SqlHash};
+use alloy::primitives::hex;
 pub use alloy::primitives::Bytes;
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
-use std::ops::Deref;
+use std::ops::{Deref, Range};
 use std::str::FromStr;
 
 /// A wrapper around `Bytes` to represent SQL byte arrays.
@@ -15,6 +17,11 @@ impl SqlBytes {
         SqlBytes(Bytes::new())
     }
 
+    /// Creates a new `SqlBytes` of `count` bytes, each set to `byte`.
+    pub fn repeat(byte: u8, count: usize) -> Self {
+        SqlBytes(Bytes::from(vec![byte; count]))
+    }
+
     /// Returns a reference to the inner `Bytes`.
     pub fn inner(&self) -> &Bytes {
         &self.0
@@ -26,6 +33,223 @@ impl SqlBytes {
     pub fn to_u256(&self) -> crate::SqlU256 {
         crate::SqlU256::from(alloy::primitives::U256::from_be_slice(self.as_ref()))
     }
+
+    /// Returns true if `s` is valid hex of any whole number of bytes, with an optional
+    /// `0x` or `0X` prefix. An empty string (or just `0x`) is valid and means no bytes.
+    ///
+    /// # Examples
+    /// ```
+    /// use ethereum_mysql::SqlBytes;
+    /// assert!(SqlBytes::is_valid_hex("0xa9059cbb"));
+    /// assert!(!SqlBytes::is_valid_hex("0xa9059cb"));
+    /// ```
+    pub fn is_valid_hex(s: &str) -> bool {
+        let digits = s
+            .strip_prefix("0x")
+            .or_else(|| s.strip_prefix("0X"))
+            .unwrap_or(s);
+        digits.len().is_multiple_of(2) && digits.bytes().all(|b| b.is_ascii_hexdigit())
+    }
+
+    /// Computes `keccak256` of the bytes.
+    pub fn keccak256(&self) -> SqlHash {
+        SqlHash::keccak256(&self.0)
+    }
+
+    /// Decodes an ABI-encoded dynamic `string` (e.g. the return data of `name()`).
+    ///
+    /// Reads the head offset, the length word and the UTF-8 payload.
+    /// Returns Err if the layout is out of bounds or the payload is not valid UTF-8.
+    pub fn decode_abi_string(&self) -> Result<String, &'static str> {
+        let payload = self.abi_dynamic_payload()?;
+        String::from_utf8(payload.to_vec()).map_err(|_| "ABI string is not valid UTF-8")
+    }
+
+    /// Decodes an ABI-encoded dynamic `bytes` value, returning the inner payload.
+    ///
+    /// Returns Err if the offset or length points outside the data.
+    pub fn decode_abi_bytes(&self) -> Result<SqlBytes, &'static str> {
+        let payload = self.abi_dynamic_payload()?;
+        Ok(SqlBytes(Bytes::copy_from_slice(payload)))
+    }
+
+    /// Returns the payload of an ABI-encoded dynamic value (offset, length, data).
+    fn abi_dynamic_payload(&self) -> Result<&[u8], &'static str> {
+        fn read_word(data: &[u8], at: usize) -> Result<usize, &'static str> {
+            let word = at
+                .checked_add(32)
+                .and_then(|end| data.get(at..end))
+                .ok_or("ABI data too short for head word")?;
+            if word[..24].iter().any(|&b| b != 0) {
+                return Err("ABI offset or length out of range");
+            }
+            let mut buf = [0u8; 8];
+            buf.copy_from_slice(&word[24..]);
+            usize::try_from(u64::from_be_bytes(buf))
+                .map_err(|_| "ABI offset or length out of range")
+        }
+
+        let data: &[u8] = self.as_ref();
+        let offset = read_word(data, 0)?;
+        let len = read_word(data, offset)?;
+        let start = offset + 32;
+        start
+            .checked_add(len)
+            .and_then(|end| data.get(start..end))
+            .ok_or("ABI payload out of bounds")
+    }
+
+    /// Formats the bytes as `0x`-prefixed hex, keeping only the first `max_bytes` bytes.
+    ///
+    /// Longer values are suffixed with `…(N bytes)` where `N` is the total length,
+    /// which keeps large blobs such as calldata readable in logs.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ethereum_mysql::SqlBytes;
+    /// use std::str::FromStr;
+    ///
+    /// let data = SqlBytes::from_str("0xa9059cbb0000").unwrap();
+    /// assert_eq!(data.to_truncated_string(4), "0xa9059cbb…(6 bytes)");
+    /// assert_eq!(data.to_truncated_string(6), "0xa9059cbb0000");
+    /// ```
+    pub fn to_truncated_string(&self, max_bytes: usize) -> String {
+        if self.len() <= max_bytes {
+            return self.to_string();
+        }
+        format!(
+            "{}…({} bytes)",
+            hex::encode_prefixed(&self[..max_bytes]),
+            self.len()
+        )
+    }
+
+    /// Splits the bytes into `N`-byte chunks, zero-padding the final chunk.
+    ///
+    /// For `N = 32` this yields the ABI words of the data. `N = 0` is rejected at
+    /// compile time:
+    ///
+    /// ```compile_fail
+    /// use ethereum_mysql::SqlBytes;
+    /// let _ = SqlBytes::new().to_fixed_chunks::<0>().count();
+    /// ```
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ethereum_mysql::{SqlBytes, SqlFixedBytes};
+    /// use std::str::FromStr;
+    ///
+    /// let data = SqlBytes::from_str("0x010203").unwrap();
+    /// let chunks: Vec<SqlFixedBytes<2>> = data.to_fixed_chunks::<2>().collect();
+    /// assert_eq!(chunks, vec![SqlFixedBytes::new([1, 2]), SqlFixedBytes::new([3, 0])]);
+    /// ```
+    pub fn to_fixed_chunks<const N: usize>(&self) -> impl Iterator<Item = SqlFixedBytes<N>> + '_ {
+        const { assert!(N > 0, "chunk size must be non-zero") };
+        self.0.chunks(N).map(|chunk| {
+            let mut buf = [0u8; N];
+            buf[..chunk.len()].copy_from_slice(chunk);
+            SqlFixedBytes::new(buf)
+        })
+    }
+
+    /// XORs two equal-length byte arrays. Returns Err if the lengths differ.
+    pub fn xor(&self, other: &SqlBytes) -> Result<SqlBytes, &'static str> {
+        if self.len() != other.len() {
+            return Err("SqlBytes length mismatch for xor");
+        }
+        let out: Vec<u8> = self.iter().zip(other.iter()).map(|(a, b)| a ^ b).collect();
+        Ok(SqlBytes(Bytes::from(out)))
+    }
+
+    /// Returns a borrowed view of `range`, or `None` if it is out of bounds.
+    ///
+    /// # Examples
+    /// ```
+    /// use ethereum_mysql::SqlBytes;
+    /// use std::str::FromStr;
+    /// let data = SqlBytes::from_str("0xa9059cbb0000").unwrap();
+    /// assert_eq!(data.slice_ref(0..4), Some(&[0xa9, 0x05, 0x9c, 0xbb][..]));
+    /// assert_eq!(data.slice_ref(4..10), None);
+    /// ```
+    pub fn slice_ref(&self, range: Range<usize>) -> Option<&[u8]> {
+        self.0.get(range)
+    }
+
+    /// Returns true if `s` is the hex encoding of these bytes.
+    ///
+    /// The `0x` prefix is optional and hex digits may be in either case.
+    /// Returns false if `s` is not valid hex.
+    ///
+    /// # Examples
+    /// ```
+    /// use ethereum_mysql::SqlBytes;
+    /// use std::str::FromStr;
+    /// let data = SqlBytes::from_str("0xdeadbeef").unwrap();
+    /// assert!(data.eq_hex_str("DEADBEEF"));
+    /// ```
+    pub fn eq_hex_str(&self, s: &str) -> bool {
+        hex::decode(s).is_ok_and(|bytes| bytes[..] == self[..])
+    }
+
+    /// Splits tightly packed 20-byte addresses into a vector.
+    ///
+    /// Returns Err if the length is not a multiple of 20. Empty input yields an empty vector.
+    pub fn as_address_array(&self) -> Result<Vec<SqlAddress>, &'static str> {
+        if !self.len().is_multiple_of(20) {
+            return Err("SqlBytes length is not a multiple of 20");
+        }
+        Ok(self
+            .0
+            .chunks_exact(20)
+            .map(SqlAddress::from_slice)
+            .collect())
+    }
+}
+
+#[cfg(feature = "compress")]
+#[cfg_attr(docsrs, doc(cfg(feature = "compress")))]
+impl SqlBytes {
+    /// Compresses the bytes with raw deflate.
+    ///
+    /// This is an explicit opt-in: storing the result changes the column content, and
+    /// the SQLx integration never compresses or decompresses automatically. Read values
+    /// back with [`decompressed`](Self::decompressed).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ethereum_mysql::SqlBytes;
+    ///
+    /// let blob = SqlBytes::repeat(0, 1024);
+    /// let packed = blob.compressed();
+    /// assert!(packed.len() < blob.len());
+    /// assert_eq!(packed.decompressed().unwrap(), blob);
+    /// ```
+    pub fn compressed(&self) -> SqlBytes {
+        use flate2::{write::DeflateEncoder, Compression};
+        use std::io::Write;
+
+        let mut encoder = DeflateEncoder::new(Vec::new(), Compression::default());
+        // Writing into a Vec cannot fail.
+        encoder.write_all(&self.0).expect("deflate into Vec failed");
+        SqlBytes(Bytes::from(
+            encoder.finish().expect("deflate into Vec failed"),
+        ))
+    }
+
+    /// Decompresses bytes produced by [`compressed`](Self::compressed).
+    ///
+    /// Returns Err if the data is not a valid deflate stream.
+    pub fn decompressed(&self) -> Result<SqlBytes, std::io::Error> {
+        use flate2::read::DeflateDecoder;
+        use std::io::Read;
+
+        let mut out = Vec::new();
+        DeflateDecoder::new(self.0.as_ref()).read_to_end(&mut out)?;
+        Ok(SqlBytes(Bytes::from(out)))
+    }
 }
 
 impl AsRef<Bytes> for SqlBytes {
@@ -89,6 +313,212 @@ mod tests {
         assert_eq!(format!("{}", sql_bytes), "0x1234");
     }
 
+    #[test]
+    fn test_repeat() {
+        let val = SqlBytes::repeat(0xff, 4);
+        assert_eq!(val.len(), 4);
+        assert_eq!(format!("{}", val), "0xffffffff");
+        assert!(SqlBytes::repeat(0xab, 0).is_empty());
+    }
+
+    #[test]
+    fn test_decode_abi_string() {
+        // abi.encode("USD Coin")
+        let encoded = SqlBytes::from_str(concat!(
+            "0x",
+            "0000000000000000000000000000000000000000000000000000000000000020",
+            "0000000000000000000000000000000000000000000000000000000000000008",
+            "55534420436f696e000000000000000000000000000000000000000000000000"
+        ))
+        .unwrap();
+        assert_eq!(encoded.decode_abi_string().unwrap(), "USD Coin");
+
+        // Length pointing past the end of the data
+        let truncated = SqlBytes::from_str(concat!(
+            "0x",
+            "0000000000000000000000000000000000000000000000000000000000000020",
+            "0000000000000000000000000000000000000000000000000000000000000040",
+            "55534420436f696e000000000000000000000000000000000000000000000000"
+        ))
+        .unwrap();
+        assert!(truncated.decode_abi_string().is_err());
+        assert!(SqlBytes::new().decode_abi_string().is_err());
+
+        // Offset that would overflow when reading the length word
+        let bad_offset = SqlBytes::from_str(
+            "0x000000000000000000000000000000000000000000000000ffffffffffffffff",
+        )
+        .unwrap();
+        assert!(bad_offset.decode_abi_string().is_err());
+    }
+
+    #[test]
+    fn test_decode_abi_bytes() {
+        // abi.encode(bytes) where the payload spans more than one word
+        let encoded = SqlBytes::from_str(concat!(
+            "0x",
+            "0000000000000000000000000000000000000000000000000000000000000020",
+            "0000000000000000000000000000000000000000000000000000000000000024",
+            "a9059cbb00000000000000000000000042066a09b94e41e0263eee935177c2ec",
+            "fff7fe1100000000000000000000000000000000000000000000000000000000"
+        ))
+        .unwrap();
+        assert_eq!(
+            encoded.decode_abi_bytes().unwrap(),
+            SqlBytes::from_str(
+                "0xa9059cbb00000000000000000000000042066a09b94e41e0263eee935177c2ecfff7fe11"
+            )
+            .unwrap()
+        );
+
+        // Empty bytes
+        let empty = SqlBytes::from_str(concat!(
+            "0x",
+            "0000000000000000000000000000000000000000000000000000000000000020",
+            "0000000000000000000000000000000000000000000000000000000000000000"
+        ))
+        .unwrap();
+        assert_eq!(empty.decode_abi_bytes().unwrap(), SqlBytes::new());
+        assert!(SqlBytes::new().decode_abi_bytes().is_err());
+    }
+
+    #[test]
+    fn test_xor() {
+        let a = SqlBytes::from_str("0xff00ff00").unwrap();
+        let b = SqlBytes::from_str("0x0f0f0f0f").unwrap();
+        assert_eq!(
+            a.xor(&b).unwrap(),
+            SqlBytes::from_str("0xf00ff00f").unwrap()
+        );
+        assert_eq!(
+            a.xor(&a).unwrap(),
+            SqlBytes::from_str("0x00000000").unwrap()
+        );
+
+        let short = SqlBytes::from_str("0x1234").unwrap();
+        assert!(a.xor(&short).is_err());
+    }
+
+    #[test]
+    fn test_as_address_array() {
+        let a = crate::sqladdress!("0x742d35Cc6635C0532925a3b8D42cC72b5c2A9A1d");
+        let b = crate::sqladdress!("0xdAC17F958D2ee523a2206206994597C13D831ec7");
+        let packed = SqlBytes::from(Bytes::from([a.as_slice(), b.as_slice()].concat()));
+        assert_eq!(packed.len(), 40);
+        assert_eq!(packed.as_address_array().unwrap(), vec![a, b]);
+
+        let odd = SqlBytes::from(Bytes::from([packed.to_vec(), vec![0u8]].concat()));
+        assert!(odd.as_address_array().is_err());
+        assert_eq!(SqlBytes::new().as_address_array().unwrap(), vec![]);
+    }
+
+    #[test]
+    fn test_is_valid_hex() {
+        for valid in ["0xdeadbeef", "deadbeef", "0xDEADbeef", "0x", ""] {
+            assert!(SqlBytes::is_valid_hex(valid), "{valid}");
+            assert!(SqlBytes::from_str(valid).is_ok(), "{valid}");
+        }
+        for invalid in ["0xdeadbee", "0x1", "0xzz", "0x 0", "0x0x00"] {
+            assert!(!SqlBytes::is_valid_hex(invalid), "{invalid}");
+        }
+        assert!(SqlBytes::is_valid_hex("0XDEADBEEF"));
+        assert!(SqlBytes::is_valid_hex("0X"));
+        assert!(!SqlBytes::is_valid_hex("0X0X00"));
+    }
+
+    #[test]
+    fn test_keccak256() {
+        let data = SqlBytes::from(Bytes::from_static(b"Transfer(address,address,uint256)"));
+        assert_eq!(
+            data.keccak256(),
+            SqlHash::from_str("0xddf252ad1be2c89b69c2b068fc378daa952ba7f163c4a11628f55a4df523b3ef")
+                .unwrap()
+        );
+    }
+
+    #[test]
+    fn test_slice_ref() {
+        let data = SqlBytes::from_str("0x0102030405").unwrap();
+        assert_eq!(data.slice_ref(1..3), Some(&[0x02, 0x03][..]));
+        assert_eq!(data.slice_ref(0..5), Some(&data[..]));
+        assert_eq!(data.slice_ref(5..5), Some(&[][..]));
+
+        assert_eq!(data.slice_ref(3..6), None);
+        assert_eq!(data.slice_ref(6..7), None);
+        #[allow(clippy::reversed_empty_ranges)]
+        let reversed = data.slice_ref(3..1);
+        assert_eq!(reversed, None);
+
+        // Borrowed from the original buffer, not copied
+        assert_eq!(data.slice_ref(2..4).unwrap().as_ptr(), data[2..].as_ptr());
+    }
+
+    #[test]
+    fn test_eq_hex_str() {
+        let data = SqlBytes::from_str("0xdeadbeef01").unwrap();
+        assert!(data.eq_hex_str("0xdeadbeef01"));
+        assert!(data.eq_hex_str("0xDEADBEEF01"));
+        assert!(data.eq_hex_str("DEADBEEF01"));
+        assert!(data.eq_hex_str("deadBEEF01"));
+
+        assert!(!data.eq_hex_str("0xdeadbeef"));
+        assert!(!data.eq_hex_str("0xdeadbeef0102"));
+        assert!(!data.eq_hex_str("not hex"));
+        assert!(!data.eq_hex_str("0xdeadbeef0"));
+
+        assert!(SqlBytes::new().eq_hex_str("0x"));
+        assert!(SqlBytes::new().eq_hex_str(""));
+    }
+
+    #[test]
+    fn test_to_fixed_chunks() {
+        let data = SqlBytes::from_str("0x0102030405060708090a").unwrap();
+        let chunks: Vec<SqlFixedBytes<4>> = data.to_fixed_chunks::<4>().collect();
+        assert_eq!(
+            chunks,
+            vec![
+                SqlFixedBytes::new([1, 2, 3, 4]),
+                SqlFixedBytes::new([5, 6, 7, 8]),
+                SqlFixedBytes::new([9, 10, 0, 0]),
+            ]
+        );
+        assert_eq!(SqlBytes::new().to_fixed_chunks::<32>().count(), 0);
+
+        // The slice method is still reachable through Deref
+        assert_eq!(data.chunks(4).count(), 3);
+    }
+
+    #[test]
+    fn test_to_truncated_string() {
+        let blob = SqlBytes::repeat(0xab, 1000);
+        assert_eq!(blob.to_truncated_string(4), "0xabababab…(1000 bytes)");
+        assert_eq!(blob.to_truncated_string(0), "0x…(1000 bytes)");
+
+        let short = SqlBytes::from_str("0x1234").unwrap();
+        assert_eq!(short.to_truncated_string(4), "0x1234");
+    }
+
+    #[cfg(feature = "compress")]
+    #[test]
+    fn test_compress_round_trip() {
+        let input = "0xa9059cbb00000000000000000000000042066a09b94e41e0263eee935177c2ecfff7fe11000000000000000000000000000000000000000000000002629f66e0c5300000";
+        let call = SqlBytes::from_str(input).unwrap();
+        let blob = SqlBytes::from(Bytes::from(call.to_vec().repeat(100)));
+
+        let packed = blob.compressed();
+        assert!(packed.len() < blob.len() / 10);
+        assert_eq!(packed.decompressed().unwrap(), blob);
+
+        assert_eq!(
+            SqlBytes::new().compressed().decompressed().unwrap(),
+            SqlBytes::new()
+        );
+        assert!(SqlBytes::from_str("0xffffffff")
+            .unwrap()
+            .decompressed()
+            .is_err());
+    }
+
     #[cfg(feature = "serde")]
     #[test]
     fn test_serde() {
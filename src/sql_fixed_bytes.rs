@@ -1,3 +1,4 @@
+use alloy::primitives::keccak256;
 pub use alloy::primitives::FixedBytes;
 use std::ops::Deref;
 use std::str::FromStr;
@@ -28,6 +29,41 @@ impl<const BYTES: usize> SqlFixedBytes<BYTES> {
     /// Creates a new `SqlFixedBytes` initialized to zero.
     pub const ZERO: Self = SqlFixedBytes(FixedBytes::ZERO);
 
+    /// Creates a new `SqlFixedBytes`, returning `None` if every byte is zero.
+    ///
+    /// Useful for rejecting empty hashes at the boundary.
+    pub fn nonzero(bytes: [u8; BYTES]) -> Option<Self> {
+        if bytes.iter().all(|&b| b == 0) {
+            None
+        } else {
+            Some(SqlFixedBytes::new(bytes))
+        }
+    }
+
+    /// Returns true if `s` is exactly `BYTES` bytes of hex, with an optional `0x` or `0X`
+    /// prefix (the same prefixes the SQLx decoder accepts).
+    ///
+    /// Use this to pre-validate user input before parsing or binding it.
+    ///
+    /// # Examples
+    /// ```
+    /// use ethereum_mysql::SqlFixedBytes;
+    /// assert!(SqlFixedBytes::<2>::is_valid_hex("0xbeef"));
+    /// assert!(!SqlFixedBytes::<2>::is_valid_hex("0xbeefee"));
+    /// ```
+    pub fn is_valid_hex(s: &str) -> bool {
+        let digits = s
+            .strip_prefix("0x")
+            .or_else(|| s.strip_prefix("0X"))
+            .unwrap_or(s);
+        digits.len() == BYTES * 2 && digits.bytes().all(|b| b.is_ascii_hexdigit())
+    }
+
+    /// Creates a new `SqlFixedBytes` with every byte set to `byte`.
+    pub const fn splat(byte: u8) -> Self {
+        SqlFixedBytes(FixedBytes::repeat_byte(byte))
+    }
+
     /// Creates a new `SqlFixedBytes` from a `FixedBytes<BYTES>`.
     pub const fn from_bytes(bytes: FixedBytes<BYTES>) -> Self {
         SqlFixedBytes(bytes)
@@ -48,6 +84,14 @@ impl<const BYTES: usize> SqlFixedBytes<BYTES> {
         None
     }
 
+    /// Computes `keccak256(self || other)`, e.g. for composite keys or event-topic building.
+    pub fn hash_with<const M: usize>(&self, other: &SqlFixedBytes<M>) -> SqlHash {
+        let mut buf = Vec::with_capacity(BYTES + M);
+        buf.extend_from_slice(self.0.as_slice());
+        buf.extend_from_slice(other.0.as_slice());
+        SqlFixedBytes(keccak256(&buf))
+    }
+
     /// Interprets the fixed bytes as a U256 (no check, always possible for 32 bytes).
     pub fn to_u256(&self) -> crate::SqlU256 {
         use crate::SqlU256;
@@ -63,12 +107,90 @@ impl<const BYTES: usize> SqlFixedBytes<BYTES> {
     }
 }
 
+impl SqlHash {
+    /// Computes `keccak256(data)`. Same as [`keccak256`](Self::keccak256).
+    pub fn from_preimage(data: impl AsRef<[u8]>) -> SqlHash {
+        Self::keccak256(data)
+    }
+
+    /// Computes `keccak256(input)`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ethereum_mysql::SqlHash;
+    ///
+    /// let selector_hash = SqlHash::keccak256("transfer(address,uint256)");
+    /// assert_eq!(&selector_hash[..4], &[0xa9, 0x05, 0x9c, 0xbb]);
+    /// ```
+    pub fn keccak256(input: impl AsRef<[u8]>) -> SqlHash {
+        SqlFixedBytes(keccak256(input))
+    }
+
+    /// Computes the topic of an event signature, e.g. `"Transfer(address,address,uint256)"`.
+    ///
+    /// This is the full 32-byte `keccak256` of the signature, as used for `topic0`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ethereum_mysql::SqlTopicHash;
+    ///
+    /// let topic = SqlTopicHash::event_signature("Approval(address,address,uint256)");
+    /// assert_eq!(
+    ///     topic.to_string(),
+    ///     "0x8c5be1e5ebec7d5bd14f71427d1e84f3dd0314c0f7b2291e5b200ac8c7c3b925"
+    /// );
+    /// ```
+    pub fn event_signature(sig: &str) -> SqlTopicHash {
+        SqlHash::from_preimage(sig.as_bytes())
+    }
+
+    /// Creates a hash from the 32-byte big-endian encoding of `value`.
+    pub fn from_u256(value: crate::SqlU256) -> SqlHash {
+        SqlFixedBytes::new(value.to_be_bytes())
+    }
+
+    /// Treats the hash as a big-endian number and adds `offset`, wrapping on overflow.
+    ///
+    /// Useful for storage slots of struct fields and fixed arrays, which sit at
+    /// consecutive offsets from a base slot.
+    ///
+    /// # Examples
+    /// ```
+    /// use ethereum_mysql::{SqlHash, SqlU256};
+    /// let base = SqlHash::from_u256(SqlU256::from(5u64));
+    /// assert_eq!(base.add_offset(2).to_u256(), SqlU256::from(7u64));
+    /// ```
+    pub fn add_offset(&self, offset: u64) -> SqlHash {
+        SqlHash::from_u256(self.to_u256().wrapping_add(crate::SqlU256::from(offset)))
+    }
+
+    /// Hashes two nodes in ascending byte order: `keccak256(min(a, b) || max(a, b))`.
+    ///
+    /// This matches OpenZeppelin's `MerkleProof._hashPair`, so the result does not
+    /// depend on argument order.
+    pub fn hash_pair_sorted(a: &SqlHash, b: &SqlHash) -> SqlHash {
+        if a <= b {
+            a.hash_with(b)
+        } else {
+            b.hash_with(a)
+        }
+    }
+}
+
 impl<const BYTES: usize> AsRef<FixedBytes<BYTES>> for SqlFixedBytes<BYTES> {
     fn as_ref(&self) -> &FixedBytes<BYTES> {
         &self.0
     }
 }
 
+impl<const BYTES: usize> AsRef<[u8]> for SqlFixedBytes<BYTES> {
+    fn as_ref(&self) -> &[u8] {
+        self.0.as_slice()
+    }
+}
+
 impl<const BYTES: usize> Deref for SqlFixedBytes<BYTES> {
     type Target = FixedBytes<BYTES>;
 
@@ -83,12 +205,36 @@ impl<const BYTES: usize> From<FixedBytes<BYTES>> for SqlFixedBytes<BYTES> {
     }
 }
 
+impl<const BYTES: usize> From<[u8; BYTES]> for SqlFixedBytes<BYTES> {
+    fn from(bytes: [u8; BYTES]) -> Self {
+        SqlFixedBytes::new(bytes)
+    }
+}
+
+impl<const BYTES: usize> From<&[u8; BYTES]> for SqlFixedBytes<BYTES> {
+    fn from(bytes: &[u8; BYTES]) -> Self {
+        SqlFixedBytes::new(*bytes)
+    }
+}
+
 impl<const BYTES: usize> From<SqlFixedBytes<BYTES>> for FixedBytes<BYTES> {
     fn from(sql_bytes: SqlFixedBytes<BYTES>) -> Self {
         sql_bytes.0
     }
 }
 
+impl<const BYTES: usize> PartialEq<[u8; BYTES]> for SqlFixedBytes<BYTES> {
+    fn eq(&self, other: &[u8; BYTES]) -> bool {
+        self.0 .0 == *other
+    }
+}
+
+impl<const BYTES: usize> PartialOrd<[u8; BYTES]> for SqlFixedBytes<BYTES> {
+    fn partial_cmp(&self, other: &[u8; BYTES]) -> Option<std::cmp::Ordering> {
+        self.0 .0.partial_cmp(other)
+    }
+}
+
 impl<const BYTES: usize> FromStr for SqlFixedBytes<BYTES> {
     type Err = <FixedBytes<BYTES> as FromStr>::Err;
 
@@ -137,6 +283,124 @@ mod tests {
         assert_eq!(as_ref, deref);
     }
 
+    #[test]
+    fn test_from_array_and_as_slice() {
+        let val = SqlFixedBytes::<4>::from([0u8; 4]);
+        assert_eq!(val, SqlFixedBytes::<4>::ZERO);
+        let slice: &[u8] = val.as_ref();
+        assert_eq!(slice, &[0u8; 4]);
+
+        let raw = [0x09, 0x5e, 0xa7, 0xb3];
+        let from_ref = SqlFixedBytes::<4>::from(&raw);
+        assert_eq!(from_ref, SqlFixedBytes::<4>::from(raw));
+        let slice: &[u8] = from_ref.as_ref();
+        assert_eq!(slice, &raw[..]);
+    }
+
+    #[test]
+    fn test_is_valid_hex() {
+        let hash = "0xddf252ad1be2c89b69c2b068fc378daa952ba7f163c4a11628f55a4df523b3ef";
+        assert!(SqlHash::is_valid_hex(hash));
+        assert!(SqlHash::is_valid_hex(&hash[2..]));
+        assert!(SqlHash::is_valid_hex(
+            &hash.to_uppercase().replace("0X", "0x")
+        ));
+        // Uppercase prefix, as accepted when decoding from the database
+        assert!(SqlHash::is_valid_hex(&hash.to_uppercase()));
+        assert!(SqlHash::is_valid_hex(&hash.replacen("0x", "0X", 1)));
+
+        // Odd length
+        assert!(!SqlHash::is_valid_hex(&hash[..65]));
+        // Valid hex, but the wrong length for N
+        assert!(!SqlFixedBytes::<20>::is_valid_hex(hash));
+        assert!(!SqlHash::is_valid_hex(&format!("{}00", hash)));
+        assert!(!SqlHash::is_valid_hex(&hash.replace('d', "g")));
+        assert!(SqlFixedBytes::<0>::is_valid_hex("0x"));
+        assert!(!SqlHash::is_valid_hex(""));
+    }
+
+    #[test]
+    fn test_nonzero() {
+        assert_eq!(SqlFixedBytes::<32>::nonzero([0u8; 32]), None);
+
+        let mut bytes = [0u8; 32];
+        bytes[31] = 1;
+        let val = SqlFixedBytes::<32>::nonzero(bytes).unwrap();
+        assert_eq!(val, SqlFixedBytes::new(bytes));
+    }
+
+    #[test]
+    fn test_keccak256() {
+        assert_eq!(
+            SqlHash::keccak256("Transfer(address,address,uint256)").to_string(),
+            "0xddf252ad1be2c89b69c2b068fc378daa952ba7f163c4a11628f55a4df523b3ef"
+        );
+        assert_eq!(SqlHash::keccak256(b""), SqlHash::from_preimage(b""));
+    }
+
+    #[test]
+    fn test_from_preimage_and_event_signature() {
+        assert_eq!(
+            SqlHash::from_preimage(b"").to_string(),
+            "0xc5d2460186f7233c927e7db2dcc703c0e500b653ca82273b7bfad8045d85a470"
+        );
+        assert_eq!(
+            SqlHash::from_preimage("abc"),
+            SqlHash::from_preimage(vec![b'a', b'b', b'c'])
+        );
+        assert_eq!(
+            SqlTopicHash::event_signature("Transfer(address,address,uint256)"),
+            SqlTopicHash::from_str(
+                "0xddf252ad1be2c89b69c2b068fc378daa952ba7f163c4a11628f55a4df523b3ef"
+            )
+            .unwrap()
+        );
+    }
+
+    #[test]
+    fn test_hash_pair_sorted() {
+        let one =
+            SqlHash::from_str("0x0000000000000000000000000000000000000000000000000000000000000001")
+                .unwrap();
+        let two =
+            SqlHash::from_str("0x0000000000000000000000000000000000000000000000000000000000000002")
+                .unwrap();
+
+        // keccak256(abi.encodePacked(uint256(1), uint256(2)))
+        let expected =
+            SqlHash::from_str("0xe90b7bceb6e7df5418fb78d8ee546e97c83a08bbccc01a0644d599ccd2a7c2e0")
+                .unwrap();
+        assert_eq!(SqlHash::hash_pair_sorted(&one, &two), expected);
+        assert_eq!(SqlHash::hash_pair_sorted(&two, &one), expected);
+        assert_ne!(two.hash_with(&one), expected);
+    }
+
+    #[test]
+    fn test_splat() {
+        let val = SqlFixedBytes::<32>::splat(0xff);
+        assert_eq!(val.len(), 32);
+        assert!(val.iter().all(|&b| b == 0xff));
+        assert_eq!(val.to_string(), format!("0x{}", "ff".repeat(32)));
+        assert_eq!(SqlFixedBytes::<4>::splat(0), SqlFixedBytes::<4>::ZERO);
+    }
+
+    #[test]
+    fn test_hash_with() {
+        let addr =
+            SqlFixedBytes::<20>::from_str("0x742d35cc6635c0532925a3b8d42cc72b5c2a9a1d").unwrap();
+        let slot = SqlFixedBytes::<32>::from_str(
+            "0x0000000000000000000000000000000000000000000000000000000000000003",
+        )
+        .unwrap();
+
+        let mut concat = addr.as_slice().to_vec();
+        concat.extend_from_slice(slot.as_slice());
+        let expected = SqlHash::from_bytes(keccak256(&concat));
+
+        assert_eq!(addr.hash_with(&slot), expected);
+        assert_ne!(slot.hash_with(&addr), expected);
+    }
+
     #[cfg(feature = "serde")]
     #[test]
     fn test_serde() {
@@ -155,6 +419,52 @@ mod tests {
         assert_eq!(val.to_string(), hex);
     }
 
+    #[test]
+    fn test_add_offset() {
+        use crate::{SqlU256, U256};
+
+        let slot = SqlHash::from_preimage(b"balances");
+        let base = slot.to_u256();
+        assert_eq!(SqlHash::from_u256(base), slot);
+
+        assert_eq!(slot.add_offset(0), slot);
+        assert_eq!(slot.add_offset(1).to_u256(), base + SqlU256::from(1u64));
+        assert_eq!(
+            slot.add_offset(u64::MAX).to_u256(),
+            base.wrapping_add(SqlU256::from(u64::MAX))
+        );
+
+        // Carries across bytes and wraps at 2^256
+        let low = SqlHash::from_u256(SqlU256::from(0xffu64));
+        assert_eq!(low.add_offset(1).to_u256(), SqlU256::from(0x100u64));
+        let max = SqlHash::splat(0xff);
+        assert_eq!(max.to_u256(), SqlU256::from(U256::MAX));
+        assert_eq!(max.add_offset(1), SqlHash::ZERO);
+        assert_eq!(max.add_offset(3).to_u256(), SqlU256::from(2u64));
+    }
+
+    #[test]
+    fn test_compare_with_raw_array() {
+        let mut raw = [0u8; 32];
+        raw[31] = 0x10;
+        let hash = SqlHash::from(raw);
+
+        assert!(hash == raw);
+        assert!(hash != [0u8; 32]);
+        assert!(hash > [0u8; 32]);
+        assert!(hash < [0xffu8; 32]);
+        assert!(hash <= raw);
+
+        // Byte-wise lexicographic order, same as between two hashes
+        let mut higher = [0u8; 32];
+        higher[0] = 0x01;
+        assert!(hash < higher);
+        assert_eq!(
+            hash.partial_cmp(&higher),
+            hash.partial_cmp(&SqlHash::from(higher))
+        );
+    }
+
     #[test]
     fn test_fixed_bytes_1() {
         let hex = "0x01";
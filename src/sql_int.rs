@@ -0,0 +1,299 @@
+pub use alloy::primitives::I256;
+use alloy::primitives::{ParseSignedError, Sign};
+use std::ops::{
+    Add, AddAssign, Deref, Div, DivAssign, Mul, MulAssign, Neg, Rem, RemAssign, Sub, SubAssign,
+};
+use std::str::FromStr;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::SqlU256;
+
+/// A SQL-compatible wrapper for 256-bit signed integers.
+///
+/// `SqlI256` wraps `alloy::primitives::I256` (two's complement) for values such as
+/// balance deltas or Solidity `int256` event fields.
+///
+/// # Format
+///
+/// `Display`, serde and the SQLx encoding all use **signed decimal** (e.g. `-42`), so
+/// stored values are readable and a database can cast them to `NUMERIC`. `FromStr`
+/// accepts signed decimal as well as sign-magnitude hex (`-0x2a`); it does not accept
+/// raw two's complement hex.
+///
+/// # Examples
+///
+/// ```rust
+/// use ethereum_mysql::SqlI256;
+/// use std::str::FromStr;
+///
+/// let delta = SqlI256::from_str("-1500").unwrap();
+/// let credit = SqlI256::from(500i64);
+/// assert_eq!((delta + credit).to_string(), "-1000");
+/// assert!(delta.is_negative());
+/// assert_eq!(-delta, SqlI256::from(1500i64));
+/// ```
+#[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct SqlI256(I256);
+
+impl SqlI256 {
+    /// Zero value.
+    pub const ZERO: Self = SqlI256(I256::ZERO);
+    /// One.
+    pub const ONE: Self = SqlI256(I256::ONE);
+    /// Minus one.
+    pub const MINUS_ONE: Self = SqlI256(I256::MINUS_ONE);
+    /// Largest representable value, `2^255 - 1`.
+    pub const MAX: Self = SqlI256(I256::MAX);
+    /// Smallest representable value, `-2^255`.
+    pub const MIN: Self = SqlI256(I256::MIN);
+
+    /// Creates a new `SqlI256` from an `I256`.
+    pub const fn new(value: I256) -> Self {
+        SqlI256(value)
+    }
+
+    /// Returns a reference to the inner `I256` value.
+    pub fn inner(&self) -> &I256 {
+        &self.0
+    }
+
+    /// Consumes self and returns the inner `I256` value.
+    pub fn into_inner(self) -> I256 {
+        self.0
+    }
+
+    /// Returns the absolute value as a `SqlU256`.
+    ///
+    /// Unlike `abs`, this cannot overflow: `SqlI256::MIN` maps to `2^255`.
+    pub fn unsigned_abs(&self) -> SqlU256 {
+        SqlU256::from(self.0.unsigned_abs())
+    }
+
+    /// Builds a value from a sign and a magnitude, or `None` if it does not fit.
+    pub fn checked_from_sign_and_abs(negative: bool, abs: SqlU256) -> Option<Self> {
+        let sign = if negative {
+            Sign::Negative
+        } else {
+            Sign::Positive
+        };
+        I256::checked_from_sign_and_abs(sign, *abs.inner()).map(SqlI256)
+    }
+}
+
+impl Deref for SqlI256 {
+    type Target = I256;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl From<I256> for SqlI256 {
+    fn from(value: I256) -> Self {
+        SqlI256(value)
+    }
+}
+
+impl From<SqlI256> for I256 {
+    fn from(value: SqlI256) -> Self {
+        value.0
+    }
+}
+
+macro_rules! impl_from_primitive {
+    ($($t:ty),*) => {
+        $(
+            impl From<$t> for SqlI256 {
+                fn from(value: $t) -> Self {
+                    SqlI256(I256::unchecked_from(value))
+                }
+            }
+        )*
+    };
+}
+
+impl_from_primitive!(i8, i16, i32, i64, i128, u8, u16, u32, u64);
+
+impl TryFrom<SqlU256> for SqlI256 {
+    type Error = &'static str;
+
+    /// Fails if the value is `2^255` or larger.
+    fn try_from(value: SqlU256) -> Result<Self, Self::Error> {
+        I256::try_from(*value.inner())
+            .map(SqlI256)
+            .map_err(|_| "value does not fit in a signed 256-bit integer")
+    }
+}
+
+impl TryFrom<SqlI256> for SqlU256 {
+    type Error = &'static str;
+
+    /// Fails for negative values.
+    fn try_from(value: SqlI256) -> Result<Self, Self::Error> {
+        if value.is_negative() {
+            return Err("negative value cannot be converted to SqlU256");
+        }
+        Ok(SqlU256::from(value.0.into_raw()))
+    }
+}
+
+impl FromStr for SqlI256 {
+    type Err = ParseSignedError;
+
+    /// Parses signed decimal (`-42`, `+42`) or sign-magnitude hex (`-0x2a`).
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        I256::from_str(s).map(SqlI256)
+    }
+}
+
+impl std::fmt::Display for SqlI256 {
+    /// Formats as signed decimal.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(&self.0, f)
+    }
+}
+
+/// Macro to implement binary operators and their assigning forms for SqlI256
+macro_rules! impl_binary_op {
+    ($trait:ident, $method:ident, $assign_trait:ident, $assign_method:ident, $op:tt) => {
+        impl $trait for SqlI256 {
+            type Output = Self;
+
+            fn $method(self, rhs: Self) -> Self::Output {
+                SqlI256(self.0 $op rhs.0)
+            }
+        }
+
+        impl $trait<&SqlI256> for SqlI256 {
+            type Output = Self;
+
+            fn $method(self, rhs: &Self) -> Self::Output {
+                SqlI256(self.0 $op rhs.0)
+            }
+        }
+
+        impl $trait<SqlI256> for &SqlI256 {
+            type Output = SqlI256;
+
+            fn $method(self, rhs: SqlI256) -> Self::Output {
+                SqlI256(self.0 $op rhs.0)
+            }
+        }
+
+        impl $trait<&SqlI256> for &SqlI256 {
+            type Output = SqlI256;
+
+            fn $method(self, rhs: &SqlI256) -> Self::Output {
+                SqlI256(self.0 $op rhs.0)
+            }
+        }
+
+        impl $assign_trait for SqlI256 {
+            fn $assign_method(&mut self, rhs: Self) {
+                self.0 = self.0 $op rhs.0;
+            }
+        }
+    };
+}
+
+impl_binary_op!(Add, add, AddAssign, add_assign, +);
+impl_binary_op!(Sub, sub, SubAssign, sub_assign, -);
+impl_binary_op!(Mul, mul, MulAssign, mul_assign, *);
+impl_binary_op!(Div, div, DivAssign, div_assign, /);
+impl_binary_op!(Rem, rem, RemAssign, rem_assign, %);
+
+impl Neg for SqlI256 {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        SqlI256(-self.0)
+    }
+}
+
+impl Neg for &SqlI256 {
+    type Output = SqlI256;
+
+    fn neg(self) -> Self::Output {
+        SqlI256(-self.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_negative() {
+        let v = SqlI256::from_str("-42").unwrap();
+        assert!(v.is_negative());
+        assert_eq!(v, SqlI256::from(-42i64));
+        assert_eq!(v.to_string(), "-42");
+        assert_eq!(SqlI256::from_str("-0x2a").unwrap(), v);
+        assert_eq!(SqlI256::from_str("+42").unwrap(), -v);
+        assert_eq!(v.unsigned_abs(), SqlU256::from(42u64));
+
+        // Extremes round-trip through Display
+        for extreme in [SqlI256::MIN, SqlI256::MAX] {
+            assert_eq!(SqlI256::from_str(&extreme.to_string()).unwrap(), extreme);
+        }
+        assert_eq!(SqlI256::MIN.unsigned_abs(), SqlU256::from(1u64) << 255usize);
+
+        assert!(SqlI256::from_str("--1").is_err());
+        assert!(SqlI256::from_str("12abc").is_err());
+        // One past MAX
+        let too_big = (SqlU256::from(1u64) << 255usize).to_string();
+        assert!(SqlI256::from_str(&too_big).is_err());
+    }
+
+    #[test]
+    fn test_arithmetic() {
+        let a = SqlI256::from(-7i64);
+        let b = SqlI256::from(3i64);
+        assert_eq!(a + b, SqlI256::from(-4i64));
+        assert_eq!(a - b, SqlI256::from(-10i64));
+        assert_eq!(a * b, SqlI256::from(-21i64));
+        // Division truncates toward zero, like Rust's primitive integers
+        assert_eq!(a / b, SqlI256::from(-2i64));
+        assert_eq!(a % b, SqlI256::from(-1i64));
+
+        let mut c = a;
+        c += b;
+        c *= SqlI256::MINUS_ONE;
+        assert_eq!(c, SqlI256::from(4i64));
+    }
+
+    #[test]
+    fn test_unsigned_conversions() {
+        let positive = SqlU256::from(1000u64);
+        let signed = SqlI256::try_from(positive).unwrap();
+        assert_eq!(SqlU256::try_from(signed).unwrap(), positive);
+        assert!(SqlU256::try_from(-signed).is_err());
+        assert!(SqlI256::try_from(SqlU256::from(crate::U256::MAX)).is_err());
+
+        assert_eq!(
+            SqlI256::checked_from_sign_and_abs(true, positive),
+            Some(SqlI256::from(-1000i64))
+        );
+        assert_eq!(
+            SqlI256::checked_from_sign_and_abs(true, SqlU256::from(1u64) << 255usize),
+            Some(SqlI256::MIN)
+        );
+        assert_eq!(
+            SqlI256::checked_from_sign_and_abs(false, SqlU256::from(1u64) << 255usize),
+            None
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde() {
+        let value = SqlI256::from(-123_456i64);
+        let json = serde_json::to_string(&value).unwrap();
+        assert_eq!(json, "\"-123456\"");
+        let de: SqlI256 = serde_json::from_str(&json).unwrap();
+        assert_eq!(de, value);
+    }
+}
@@ -0,0 +1,195 @@
+use crate::{SqlFixedBytes, SqlHash};
+use alloy::primitives::{FixedBytes, Signature, SignatureError};
+use std::ops::Deref;
+use std::str::FromStr;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// A SQL-compatible wrapper for 65-byte `r || s || v` ECDSA signatures.
+///
+/// The raw bytes are kept as-is, so the stored `v` byte (27/28, 0/1, ...) survives a
+/// database round trip. `Display` and the SQLx encoding produce a `0x`-prefixed,
+/// 132-character lowercase hex string; parsing rejects anything that is not exactly
+/// 65 bytes. Use [`to_signature`](Self::to_signature) to get alloy's [`Signature`].
+///
+/// # Examples
+///
+/// ```rust
+/// use ethereum_mysql::SqlSignature;
+/// use std::str::FromStr;
+///
+/// let sig = SqlSignature::from_str(&format!("0x{}{}1b", "11".repeat(32), "22".repeat(32))).unwrap();
+/// assert_eq!(sig.v(), 27);
+/// assert_eq!(sig.to_string().len(), 132);
+/// assert!(SqlSignature::from_str("0x1122").is_err());
+/// ```
+#[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct SqlSignature(FixedBytes<65>);
+
+impl SqlSignature {
+    /// All-zero signature, useful as a placeholder.
+    pub const ZERO: Self = SqlSignature(FixedBytes::ZERO);
+
+    /// Creates a new `SqlSignature` from raw `r || s || v` bytes.
+    pub fn new(bytes: [u8; 65]) -> Self {
+        SqlSignature(FixedBytes::new(bytes))
+    }
+
+    /// Returns a reference to the inner `FixedBytes<65>`.
+    pub fn inner(&self) -> &FixedBytes<65> {
+        &self.0
+    }
+
+    /// Returns the `r` component (bytes `0..32`).
+    pub fn r(&self) -> SqlHash {
+        SqlHash::from_bytes(FixedBytes::from_slice(&self.0[..32]))
+    }
+
+    /// Returns the `s` component (bytes `32..64`).
+    pub fn s(&self) -> SqlHash {
+        SqlHash::from_bytes(FixedBytes::from_slice(&self.0[32..64]))
+    }
+
+    /// Returns the recovery byte `v` (byte `64`), usually 27 or 28.
+    pub fn v(&self) -> u8 {
+        self.0[64]
+    }
+
+    /// Converts to alloy's [`Signature`].
+    ///
+    /// Returns Err if `v` is not a valid recovery byte (27/28, 0/1 or EIP-155 encoded).
+    pub fn to_signature(&self) -> Result<Signature, SignatureError> {
+        Signature::from_raw_array(&self.0 .0)
+    }
+}
+
+impl Deref for SqlSignature {
+    type Target = FixedBytes<65>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl AsRef<[u8]> for SqlSignature {
+    fn as_ref(&self) -> &[u8] {
+        self.0.as_slice()
+    }
+}
+
+impl From<[u8; 65]> for SqlSignature {
+    fn from(bytes: [u8; 65]) -> Self {
+        SqlSignature::new(bytes)
+    }
+}
+
+impl From<FixedBytes<65>> for SqlSignature {
+    fn from(bytes: FixedBytes<65>) -> Self {
+        SqlSignature(bytes)
+    }
+}
+
+impl From<SqlFixedBytes<65>> for SqlSignature {
+    fn from(bytes: SqlFixedBytes<65>) -> Self {
+        SqlSignature(*bytes.inner())
+    }
+}
+
+impl From<SqlSignature> for SqlFixedBytes<65> {
+    fn from(sig: SqlSignature) -> Self {
+        SqlFixedBytes::from(sig.0)
+    }
+}
+
+impl From<Signature> for SqlSignature {
+    /// Encodes `v` as 27 or 28.
+    fn from(sig: Signature) -> Self {
+        SqlSignature::new(sig.as_bytes())
+    }
+}
+
+impl FromStr for SqlSignature {
+    type Err = <FixedBytes<65> as FromStr>::Err;
+
+    /// Parses 130 hex digits, with or without a `0x` prefix.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        FixedBytes::<65>::from_str(s).map(SqlSignature)
+    }
+}
+
+impl std::fmt::Display for SqlSignature {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl Default for SqlSignature {
+    fn default() -> Self {
+        Self::ZERO
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SIG: &str = "0x9328da16089fcba9bececa81663203989f2df5fe1faa6291a45381c81bd17f76\
+                       139c6d6b623b42da56557e5e734a43dc83345ddfadec52cbe24d0cc64f550793\
+                       1c";
+
+    #[test]
+    fn test_components() {
+        let sig = SqlSignature::from_str(SIG).unwrap();
+        assert_eq!(
+            sig.r(),
+            SqlHash::from_str("0x9328da16089fcba9bececa81663203989f2df5fe1faa6291a45381c81bd17f76")
+                .unwrap()
+        );
+        assert_eq!(
+            sig.s(),
+            SqlHash::from_str("0x139c6d6b623b42da56557e5e734a43dc83345ddfadec52cbe24d0cc64f550793")
+                .unwrap()
+        );
+        assert_eq!(sig.v(), 28);
+        assert_eq!(sig.to_string(), SIG);
+        assert_eq!(sig.to_string().len(), 132);
+    }
+
+    #[test]
+    fn test_rejects_wrong_length() {
+        assert!(SqlSignature::from_str(&SIG[..130]).is_err());
+        assert!(SqlSignature::from_str(&format!("{SIG}00")).is_err());
+        assert!(SqlSignature::from_str("").is_err());
+        // Without the prefix is accepted
+        assert!(SqlSignature::from_str(&SIG[2..]).is_ok());
+    }
+
+    #[test]
+    fn test_alloy_signature_round_trip() {
+        let sig = SqlSignature::from_str(SIG).unwrap();
+        let alloy_sig = sig.to_signature().unwrap();
+        assert!(alloy_sig.v());
+        assert_eq!(SqlSignature::from(alloy_sig), sig);
+
+        // v = 1 is accepted but normalized to 28 on the way back
+        let mut raw = sig.0 .0;
+        raw[64] = 1;
+        let normalized = SqlSignature::from(SqlSignature::new(raw).to_signature().unwrap());
+        assert_eq!(normalized, sig);
+
+        raw[64] = 5;
+        assert!(SqlSignature::new(raw).to_signature().is_err());
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde() {
+        let sig = SqlSignature::from_str(SIG).unwrap();
+        let json = serde_json::to_string(&sig).unwrap();
+        assert_eq!(json, format!("\"{SIG}\""));
+        let de: SqlSignature = serde_json::from_str(&json).unwrap();
+        assert_eq!(de, sig);
+    }
+}
@@ -1,3 +1,5 @@
+use alloy::primitives::ruint::{BaseConvertError, ParseError};
+use alloy::primitives::utils::UnitsError;
 pub use alloy::primitives::Uint;
 pub use alloy::primitives::U256;
 use std::ops::Deref;
@@ -10,6 +12,8 @@ mod convert;
 mod operation;
 mod primitive_ops;
 
+pub use convert::ConversionError;
+
 /// A SQL-compatible wrapper for 256-bit unsigned integers.
 ///
 /// `SqlU256` wraps `alloy::primitives::U256` and implements all necessary traits
@@ -57,6 +61,49 @@ mod primitive_ops;
 pub struct SqlUint<const BITS: usize, const LIMBS: usize>(Uint<BITS, LIMBS>);
 /// A type alias for a 256-bit unsigned integer, commonly used for Ethereum values.
 pub type SqlU256 = SqlUint<256, 4>;
+/// A type alias for a 128-bit unsigned integer.
+pub type SqlU128 = SqlUint<128, 2>;
+
+/// Error returned when parsing a `SqlUint` from a string fails.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ParseUintError {
+    /// The input starts with a `-` sign, which is not valid for an unsigned type.
+    Negative,
+    /// The input could not be parsed as a decimal or hexadecimal integer.
+    Invalid(ParseError),
+    /// A hex-only parser was given input without a `0x` prefix.
+    MissingHexPrefix,
+    /// The parsed value does not fit in the target type.
+    Overflow {
+        /// Bit width of the target type.
+        bits: usize,
+    },
+}
+
+impl std::fmt::Display for ParseUintError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ParseUintError::Negative => {
+                write!(f, "negative values are not allowed for unsigned type")
+            }
+            ParseUintError::Invalid(e) => write!(f, "invalid unsigned integer: {}", e),
+            ParseUintError::MissingHexPrefix => {
+                write!(f, "expected a 0x-prefixed hexadecimal string")
+            }
+            ParseUintError::Overflow { bits } => {
+                write!(f, "value does not fit in {}-bit unsigned type", bits)
+            }
+        }
+    }
+}
+
+impl std::error::Error for ParseUintError {}
+
+impl From<ParseError> for ParseUintError {
+    fn from(e: ParseError) -> Self {
+        ParseUintError::Invalid(e)
+    }
+}
 
 impl<const BITS: usize, const LIMBS: usize> SqlUint<BITS, LIMBS> {
     /// Creates a new `SqlUint` from a `Uint` value.
@@ -86,56 +133,286 @@ impl<const BITS: usize, const LIMBS: usize> SqlUint<BITS, LIMBS> {
     pub fn into_inner(self) -> Uint<BITS, LIMBS> {
         self.0
     }
+
+    /// Parses a string, reporting values above the type's `MAX` as
+    /// [`ParseUintError::Overflow`] instead of a generic parse error.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ethereum_mysql::{ParseUintError, SqlU128};
+    ///
+    /// assert!(SqlU128::from_str_checked("340282366920938463463374607431768211455").is_ok());
+    /// assert_eq!(
+    ///     SqlU128::from_str_checked("340282366920938463463374607431768211456"),
+    ///     Err(ParseUintError::Overflow { bits: 128 })
+    /// );
+    /// ```
+    pub fn from_str_checked(s: &str) -> Result<Self, ParseUintError> {
+        match Self::from_str(s) {
+            Err(ParseUintError::Invalid(ParseError::BaseConvertError(
+                BaseConvertError::Overflow,
+            ))) => Err(ParseUintError::Overflow { bits: BITS }),
+            other => other,
+        }
+    }
+
+    /// Parses a string that may carry a JavaScript BigInt `n` suffix (e.g. `"1000n"`).
+    ///
+    /// The radix prefix (`0x`, `0o`, `0b`, any case) is identified first, then a single
+    /// trailing `n` is stripped from the digits that follow it, so `"0x2an"`, `"0o52n"` and
+    /// `"0b101010n"` all parse as 42, like the equivalent JS BigInt literals. A suffix with
+    /// no digits before it (`"n"`, `"0xn"`) is rejected. Everything else is parsed as by
+    /// [`FromStr`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ethereum_mysql::SqlU256;
+    ///
+    /// assert_eq!(SqlU256::from_str_js("1000n").unwrap(), SqlU256::from(1000u64));
+    /// assert_eq!(SqlU256::from_str_js("0x2an").unwrap(), SqlU256::from(42u64));
+    /// assert!(SqlU256::from_str_js("0xn").is_err());
+    /// ```
+    pub fn from_str_js(s: &str) -> Result<Self, ParseUintError> {
+        let unsigned = s.strip_prefix(['+', '-']).unwrap_or(s);
+        let digits = ["0x", "0X", "0o", "0O", "0b", "0B"]
+            .iter()
+            .find_map(|prefix| unsigned.strip_prefix(prefix))
+            .unwrap_or(unsigned);
+        match digits.strip_suffix('n') {
+            Some("") => Err(ParseUintError::Invalid(ParseError::InvalidDigit('n'))),
+            Some(_) => Self::from_str(&s[..s.len() - 1]),
+            None => Self::from_str(s),
+        }
+    }
+
+    /// Parses a `0x`-prefixed hexadecimal string, rejecting decimal input.
+    ///
+    /// Unlike [`FromStr`], input such as `"123"` is not accepted as decimal; it returns
+    /// [`ParseUintError::MissingHexPrefix`]. Overflow is reported as
+    /// [`ParseUintError::Overflow`].
+    ///
+    /// # Examples
+    /// ```
+    /// use ethereum_mysql::{ParseUintError, SqlU256};
+    /// assert_eq!(SqlU256::from_hex_str("0x7b"), Ok(SqlU256::from(123u64)));
+    /// assert_eq!(SqlU256::from_hex_str("123"), Err(ParseUintError::MissingHexPrefix));
+    /// ```
+    pub fn from_hex_str(s: &str) -> Result<Self, ParseUintError> {
+        if !s.starts_with("0x") && !s.starts_with("0X") {
+            return Err(ParseUintError::MissingHexPrefix);
+        }
+        Self::from_str_checked(s)
+    }
 }
 
 impl SqlU256 {
     /// The number of wei in one ether (10^18).
     pub const ETHER: Self = Self(U256::from_limbs([0x0, 0x8AC7230489E80000, 0, 0]));
 
+    /// Returns a reference to the underlying `ruint` value.
+    ///
+    /// `alloy::primitives::U256` is `ruint::aliases::U256`, so this is the same value as
+    /// [`inner`](SqlUint::inner) (and `Deref`); it is provided to make ruint interop explicit.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ethereum_mysql::{ruint, SqlU256};
+    ///
+    /// let value = SqlU256::from(1024u64);
+    /// let raw: &ruint::aliases::U256 = value.as_ruint();
+    /// assert!(raw.is_power_of_two());
+    /// ```
+    pub fn as_ruint(&self) -> &alloy::primitives::ruint::aliases::U256 {
+        &self.0
+    }
+
+    /// Parses many strings at once, keeping the index of each failed input.
+    ///
+    /// Each failure is reported as `(index, error message)` so callers can point
+    /// at the offending row.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ethereum_mysql::SqlU256;
+    ///
+    /// let results = SqlU256::parse_all(&["42", "0x2a", "oops"]);
+    /// assert_eq!(results[0], Ok(SqlU256::from(42u64)));
+    /// assert_eq!(results[2].as_ref().unwrap_err().0, 2);
+    /// ```
+    pub fn parse_all(inputs: &[&str]) -> Vec<Result<SqlU256, (usize, String)>> {
+        inputs
+            .iter()
+            .enumerate()
+            .map(|(i, s)| SqlU256::from_str(s).map_err(|e| (i, e.to_string())))
+            .collect()
+    }
+
     /// Creates a SqlU256 from a big-endian byte slice (pads/truncates as alloy U256).
     pub fn from_be_slice(bytes: &[u8]) -> Self {
         Self(alloy::primitives::U256::from_be_slice(bytes))
     }
 
+    /// Creates a SqlU256 from a little-endian byte slice (pads/truncates as alloy U256).
+    pub fn from_le_slice(bytes: &[u8]) -> Self {
+        Self(alloy::primitives::U256::from_le_slice(bytes))
+    }
+
+    /// Formats the value as `0x` followed by at least `width` lowercase hex digits,
+    /// zero-padded on the left. Longer values are not truncated.
+    ///
+    /// # Examples
+    /// ```
+    /// use ethereum_mysql::SqlU256;
+    /// assert_eq!(SqlU256::from(255u64).to_hex_padded(4), "0x00ff");
+    /// assert_eq!(SqlU256::from(0x12345u64).to_hex_padded(4), "0x12345");
+    /// ```
+    pub fn to_hex_padded(&self, width: usize) -> String {
+        format!("0x{:0width$x}", self.0, width = width)
+    }
+
+    /// Reinterprets the 256 bits as a two's complement signed value, like Solidity's
+    /// `int256(uint256(x))`. Values at or above `2^255` become negative.
+    ///
+    /// # Examples
+    /// ```
+    /// use ethereum_mysql::{SqlI256, SqlU256, U256};
+    /// assert_eq!(SqlU256::from(U256::MAX).to_i256_twos_complement(), SqlI256::MINUS_ONE);
+    /// ```
+    pub fn to_i256_twos_complement(&self) -> crate::SqlI256 {
+        crate::SqlI256::from(crate::I256::from_raw(self.0))
+    }
+
+    /// Returns the raw two's complement bits of `value`, like Solidity's
+    /// `uint256(int256(x))`. Inverse of [`to_i256_twos_complement`](Self::to_i256_twos_complement).
+    pub fn from_i256_bits(value: crate::SqlI256) -> Self {
+        SqlU256::from(value.into_inner().into_raw())
+    }
+
+    /// Returns the value as 32 big-endian bytes.
+    pub fn to_be_bytes(&self) -> [u8; 32] {
+        self.0.to_be_bytes::<32>()
+    }
+
+    /// Returns the value as 32 little-endian bytes.
+    pub fn to_le_bytes(&self) -> [u8; 32] {
+        self.0.to_le_bytes::<32>()
+    }
+
     /// Try to convert this value to u8. Returns Err if out of range.
-    pub fn as_u8(&self) -> Result<u8, &'static str> {
-        if self.0 > U256::from(u8::MAX) {
-            Err("SqlU256 value too large for u8")
-        } else {
-            Ok(self.0.to::<u8>())
-        }
+    pub fn as_u8(&self) -> Result<u8, ConversionError> {
+        u8::try_from(*self)
     }
     /// Try to convert this value to u16. Returns Err if out of range.
-    pub fn as_u16(&self) -> Result<u16, &'static str> {
-        if self.0 > U256::from(u16::MAX) {
-            Err("SqlU256 value too large for u16")
-        } else {
-            Ok(self.0.to::<u16>())
-        }
+    pub fn as_u16(&self) -> Result<u16, ConversionError> {
+        u16::try_from(*self)
     }
     /// Try to convert this value to u32. Returns Err if out of range.
-    pub fn as_u32(&self) -> Result<u32, &'static str> {
-        if self.0 > U256::from(u32::MAX) {
-            Err("SqlU256 value too large for u32")
-        } else {
-            Ok(self.0.to::<u32>())
-        }
+    pub fn as_u32(&self) -> Result<u32, ConversionError> {
+        u32::try_from(*self)
     }
     /// Try to convert this value to u64. Returns Err if out of range.
-    pub fn as_u64(&self) -> Result<u64, &'static str> {
-        if self.0 > U256::from(u64::MAX) {
-            Err("SqlU256 value too large for u64")
-        } else {
-            Ok(self.0.to::<u64>())
-        }
+    pub fn as_u64(&self) -> Result<u64, ConversionError> {
+        u64::try_from(*self)
     }
     /// Try to convert this value to u128. Returns Err if out of range.
-    pub fn as_u128(&self) -> Result<u128, &'static str> {
-        if self.0 > U256::from(u128::MAX) {
-            Err("SqlU256 value too large for u128")
-        } else {
-            Ok(self.0.to::<u128>())
+    pub fn as_u128(&self) -> Result<u128, ConversionError> {
+        u128::try_from(*self)
+    }
+
+    /// Converts this value to u8, clamping to `u8::MAX` if out of range.
+    pub fn saturating_to_u8(&self) -> u8 {
+        u8::try_from(self.0).unwrap_or(u8::MAX)
+    }
+    /// Converts this value to u16, clamping to `u16::MAX` if out of range.
+    pub fn saturating_to_u16(&self) -> u16 {
+        u16::try_from(self.0).unwrap_or(u16::MAX)
+    }
+    /// Converts this value to u32, clamping to `u32::MAX` if out of range.
+    pub fn saturating_to_u32(&self) -> u32 {
+        u32::try_from(self.0).unwrap_or(u32::MAX)
+    }
+    /// Converts this value to u64, clamping to `u64::MAX` if out of range.
+    pub fn saturating_to_u64(&self) -> u64 {
+        u64::try_from(self.0).unwrap_or(u64::MAX)
+    }
+    /// Converts this value to u128, clamping to `u128::MAX` if out of range.
+    pub fn saturating_to_u128(&self) -> u128 {
+        u128::try_from(self.0).unwrap_or(u128::MAX)
+    }
+
+    /// Try to convert this value to a narrower `SqlUint`. Returns None if out of range.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ethereum_mysql::{SqlU128, SqlU256};
+    /// use alloy::primitives::U128;
+    ///
+    /// let small = SqlU256::from(42u64);
+    /// assert_eq!(small.try_to_uint::<128, 2>(), Some(SqlU128::from(U128::from(42u64))));
+    ///
+    /// let large = SqlU256::from(u128::MAX) + SqlU256::from(1u64);
+    /// assert_eq!(large.try_to_uint::<128, 2>(), None);
+    /// ```
+    pub fn try_to_uint<const BITS: usize, const LIMBS: usize>(
+        &self,
+    ) -> Option<SqlUint<BITS, LIMBS>> {
+        Uint::<BITS, LIMBS>::checked_from_limbs_slice(self.0.as_limbs()).map(SqlUint)
+    }
+
+    /// Parses a decimal amount with the given number of decimals (e.g. 6 for USDC, 18 for ETH).
+    ///
+    /// Same as [`utils::parse_suint`](crate::utils::parse_suint).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ethereum_mysql::SqlU256;
+    ///
+    /// let usdc = SqlU256::from_units("12.5", 6).unwrap();
+    /// assert_eq!(usdc, SqlU256::from(12_500_000u64));
+    /// assert_eq!(usdc.to_units(6).unwrap(), "12.500000");
+    /// ```
+    pub fn from_units(s: &str, decimals: u8) -> Result<SqlU256, UnitsError> {
+        crate::utils::parse_suint(s, decimals)
+    }
+
+    /// Formats the value as a decimal amount with the given number of decimals.
+    ///
+    /// Same as [`utils::format_suint`](crate::utils::format_suint).
+    pub fn to_units(&self, decimals: u8) -> Result<String, UnitsError> {
+        crate::utils::format_suint(*self, decimals)
+    }
+
+    /// Returns the full 256-bit binary representation (256 characters, no `0b` prefix).
+    pub fn to_binary_string(&self) -> String {
+        format!("{:0256b}", self.0)
+    }
+
+    /// Parses a binary string of up to 256 `0`/`1` digits (no `0b` prefix).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ethereum_mysql::SqlU256;
+    ///
+    /// assert_eq!(SqlU256::from_binary_string("101").unwrap(), SqlU256::from(5u64));
+    /// assert!(SqlU256::from_binary_string("102").is_err());
+    /// ```
+    pub fn from_binary_string(s: &str) -> Result<SqlU256, &'static str> {
+        if s.is_empty() || s.len() > 256 {
+            return Err("binary string must have 1 to 256 digits");
         }
+        if !s.bytes().all(|b| b == b'0' || b == b'1') {
+            return Err("binary string contains non-binary digit");
+        }
+        U256::from_str_radix(s, 2)
+            .map(SqlUint)
+            .map_err(|_| "invalid binary string")
     }
 }
 
@@ -165,16 +442,42 @@ impl<const BITS: usize, const LIMBS: usize> From<SqlUint<BITS, LIMBS>> for Uint<
 }
 
 impl<const BITS: usize, const LIMBS: usize> FromStr for SqlUint<BITS, LIMBS> {
-    type Err = <Uint<BITS, LIMBS> as FromStr>::Err;
+    type Err = ParseUintError;
 
+    /// Parses a decimal or `0x`-prefixed hexadecimal string.
+    ///
+    /// A single leading `+` is accepted on decimal inputs. Inputs with a leading `-`
+    /// are rejected with [`ParseUintError::Negative`].
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        Uint::from_str(s).map(SqlUint)
+        if s.starts_with('-') {
+            return Err(ParseUintError::Negative);
+        }
+        let digits = match s.strip_prefix('+') {
+            Some(rest) if !rest.starts_with("0x") && !rest.starts_with("0X") => rest,
+            _ => s,
+        };
+        Ok(SqlUint(Uint::from_str(digits)?))
     }
 }
 
 impl<const BITS: usize, const LIMBS: usize> std::fmt::Display for SqlUint<BITS, LIMBS> {
+    /// Formats as minimal `0x`-prefixed lowercase hex.
+    ///
+    /// With the `+` flag (`{:+}`), the value is zero-padded to the full word width
+    /// instead, e.g. 66 characters (`0x` + 64 digits) for `SqlU256`.
+    ///
+    /// ```
+    /// use ethereum_mysql::SqlU256;
+    /// let v = SqlU256::from(255u64);
+    /// assert_eq!(format!("{}", v), "0xff");
+    /// assert_eq!(format!("{:+}", v), format!("0x{:0>64}", "ff"));
+    /// ```
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "0x{:x}", self.0)
+        if f.sign_plus() {
+            write!(f, "0x{:0width$x}", self.0, width = BITS / 4)
+        } else {
+            write!(f, "0x{:x}", self.0)
+        }
     }
 }
 
@@ -307,6 +610,7 @@ impl PartialOrd<crate::SqlU256> for u128 {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use alloy::primitives::U128;
 
     #[cfg(feature = "serde")]
     #[test]
@@ -319,6 +623,133 @@ mod tests {
         assert_eq!(s_value, de);
     }
 
+    #[test]
+    fn test_try_to_uint() {
+        let small = SqlU256::from(42u64);
+        let narrowed: Option<SqlU128> = small.try_to_uint();
+        assert_eq!(narrowed, Some(SqlU128::from(U128::from(42u64))));
+
+        let max_u128 = SqlU256::from(u128::MAX);
+        assert_eq!(
+            max_u128.try_to_uint::<128, 2>(),
+            Some(SqlU128::from(U128::MAX))
+        );
+
+        let too_large = max_u128 + SqlU256::from(1u64);
+        assert_eq!(too_large.try_to_uint::<128, 2>(), None);
+    }
+
+    #[test]
+    fn test_i256_twos_complement() {
+        use crate::SqlI256;
+
+        let small = SqlU256::from(42u64);
+        assert_eq!(small.to_i256_twos_complement(), SqlI256::from(42i64));
+        assert_eq!(SqlU256::from_i256_bits(SqlI256::from(42i64)), small);
+
+        let all_ones = SqlU256::from(U256::MAX);
+        assert_eq!(all_ones.to_i256_twos_complement(), SqlI256::MINUS_ONE);
+        assert_eq!(SqlU256::from_i256_bits(SqlI256::MINUS_ONE), all_ones);
+
+        // Only the sign bit set
+        let sign_bit = SqlU256::from(1u64) << 255usize;
+        assert_eq!(sign_bit.to_i256_twos_complement(), SqlI256::MIN);
+        assert_eq!(SqlU256::from_i256_bits(SqlI256::MIN), sign_bit);
+        assert_eq!(
+            (sign_bit - SqlU256::from(1u64)).to_i256_twos_complement(),
+            SqlI256::MAX
+        );
+    }
+
+    #[test]
+    fn test_display_plus_flag_pads_to_word() {
+        let v = SqlU256::from(255u64);
+        assert_eq!(format!("{}", v), "0xff");
+        assert_eq!(format!("{:+}", v).len(), 66);
+        assert_eq!(format!("{:+}", v), v.to_hex_padded(64));
+        assert_eq!(
+            format!("{:+}", SqlU256::ZERO),
+            format!("0x{}", "0".repeat(64))
+        );
+        assert_eq!(
+            format!("{:+}", SqlU256::from(U256::MAX)),
+            format!("{}", SqlU256::from(U256::MAX))
+        );
+
+        // Width follows the type
+        assert_eq!(format!("{:+}", SqlU128::from(U128::from(1u64))).len(), 34);
+    }
+
+    #[test]
+    fn test_to_hex_padded() {
+        let v = SqlU256::from(255u64);
+        assert_eq!(v.to_hex_padded(64), format!("0x{}ff", "0".repeat(62)));
+        assert_eq!(v.to_hex_padded(64).len(), 66);
+        assert_eq!(v.to_hex_padded(4), "0x00ff");
+        assert_eq!(v.to_hex_padded(2), "0xff");
+        assert_eq!(v.to_hex_padded(0), "0xff");
+
+        // No truncation when the value is wider than requested
+        assert_eq!(SqlU256::from(0x12345u64).to_hex_padded(4), "0x12345");
+        assert_eq!(
+            SqlU256::from(U256::MAX).to_hex_padded(8),
+            format!("0x{}", "f".repeat(64))
+        );
+        assert_eq!(SqlU256::ZERO.to_hex_padded(3), "0x000");
+    }
+
+    #[test]
+    fn test_byte_order_round_trip() {
+        let mid = SqlU256::from_str("0x0102030405060708090a0b0c0d0e0f10").unwrap();
+        let be = mid.to_be_bytes();
+        let le = mid.to_le_bytes();
+        assert_eq!(be[31], 0x10);
+        assert_eq!(be[16], 0x01);
+        assert_eq!(le[0], 0x10);
+        assert_eq!(le[15], 0x01);
+
+        for value in [SqlU256::ZERO, mid, SqlU256::from(U256::MAX)] {
+            let be = value.to_be_bytes();
+            let mut le = value.to_le_bytes();
+            assert_eq!(SqlU256::from_be_slice(&be), value);
+            assert_eq!(SqlU256::from_le_slice(&le), value);
+            le.reverse();
+            assert_eq!(le, be);
+        }
+
+        // Short little-endian input is zero-extended at the high end
+        assert_eq!(
+            SqlU256::from_le_slice(&[0x2a, 0x01]),
+            SqlU256::from(0x012au64)
+        );
+    }
+
+    #[test]
+    fn test_saturating_to_primitives() {
+        let small = SqlU256::from(200u64);
+        assert_eq!(small.saturating_to_u8(), 200);
+        assert_eq!(small.saturating_to_u16(), 200);
+        assert_eq!(small.saturating_to_u32(), 200);
+        assert_eq!(small.saturating_to_u64(), 200);
+        assert_eq!(small.saturating_to_u128(), 200);
+
+        assert_eq!(SqlU256::from(256u64).saturating_to_u8(), u8::MAX);
+        assert_eq!(SqlU256::from(65_536u64).saturating_to_u16(), u16::MAX);
+        assert_eq!(SqlU256::from(1u64 << 32).saturating_to_u32(), u32::MAX);
+        assert_eq!(
+            SqlU256::from(u128::from(u64::MAX) + 1).saturating_to_u64(),
+            u64::MAX
+        );
+        assert_eq!(
+            (SqlU256::from(u128::MAX) + SqlU256::from(1u64)).saturating_to_u128(),
+            u128::MAX
+        );
+
+        // Exactly MAX is not clamped, just converted.
+        assert_eq!(SqlU256::from(u64::MAX).saturating_to_u64(), u64::MAX);
+        assert_eq!(SqlU256::from(U256::MAX).saturating_to_u128(), u128::MAX);
+    }
+
     #[test]
     fn test_creation_and_constants() {
         // Test ZERO constant
@@ -359,6 +790,127 @@ mod tests {
         assert_eq!(deref_val, U256::from(42u64));
     }
 
+    #[test]
+    fn test_from_str_checked() {
+        let max = SqlU128::from_str_checked("340282366920938463463374607431768211455").unwrap();
+        assert_eq!(max, SqlU128::from(U128::MAX));
+        assert_eq!(
+            SqlU128::from_str_checked("0xff").unwrap(),
+            SqlU128::from(U128::from(255u64))
+        );
+
+        let err = SqlU128::from_str_checked("340282366920938463463374607431768211456").unwrap_err();
+        assert_eq!(err, ParseUintError::Overflow { bits: 128 });
+        assert_eq!(
+            err.to_string(),
+            "value does not fit in 128-bit unsigned type"
+        );
+        assert_eq!(
+            SqlU128::from_str_checked("0x100000000000000000000000000000000"),
+            Err(ParseUintError::Overflow { bits: 128 })
+        );
+
+        assert!(matches!(
+            SqlU128::from_str_checked("nope"),
+            Err(ParseUintError::Invalid(_))
+        ));
+    }
+
+    #[test]
+    fn test_from_str_js() {
+        assert_eq!(
+            SqlU256::from_str_js("1000n").unwrap(),
+            SqlU256::from(1000u64)
+        );
+        assert_eq!(
+            SqlU256::from_str_js("1000").unwrap(),
+            SqlU256::from(1000u64)
+        );
+        assert_eq!(SqlU256::from_str_js("0x2a").unwrap(), SqlU256::from(42u64));
+
+        // The suffix is stripped after the radix prefix, for every radix
+        for literal in ["0x2an", "0X2An", "0o52n", "0b101010n"] {
+            assert_eq!(
+                SqlU256::from_str_js(literal).unwrap(),
+                SqlU256::from(42u64),
+                "{literal}"
+            );
+        }
+        assert_eq!(SqlU256::from_str_js("0o52").unwrap(), SqlU256::from(42u64));
+        assert_eq!(
+            SqlU256::from_str_js("0b101010").unwrap(),
+            SqlU256::from(42u64)
+        );
+
+        // Only a single suffix is stripped, and it needs digits before it
+        for invalid in [
+            "1000nn", "0x2ann", "0b101nn", "n", "0xn", "0on", "0bn", "0b102n",
+        ] {
+            assert!(SqlU256::from_str_js(invalid).is_err(), "{invalid}");
+        }
+        assert_eq!(
+            SqlU256::from_str_js("-1n").unwrap_err(),
+            ParseUintError::Negative
+        );
+    }
+
+    #[test]
+    fn test_from_hex_str() {
+        assert_eq!(SqlU256::from_hex_str("0x7b"), Ok(SqlU256::from(123u64)));
+        assert_eq!(SqlU256::from_hex_str("0X7B"), Ok(SqlU256::from(123u64)));
+        assert_eq!(
+            SqlU256::from_hex_str("123"),
+            Err(ParseUintError::MissingHexPrefix)
+        );
+        assert_eq!(
+            SqlU256::from_hex_str("7b"),
+            Err(ParseUintError::MissingHexPrefix)
+        );
+        assert_eq!(
+            SqlU256::from_hex_str("-0x1"),
+            Err(ParseUintError::MissingHexPrefix)
+        );
+        assert!(matches!(
+            SqlU256::from_hex_str("0xzz"),
+            Err(ParseUintError::Invalid(_))
+        ));
+        assert_eq!(
+            SqlU128::from_hex_str(&format!("0x1{}", "0".repeat(32))),
+            Err(ParseUintError::Overflow { bits: 128 })
+        );
+        assert_eq!(
+            ParseUintError::MissingHexPrefix.to_string(),
+            "expected a 0x-prefixed hexadecimal string"
+        );
+    }
+
+    #[test]
+    fn test_parse_all() {
+        let results = SqlU256::parse_all(&["123", "0x7b", "not_a_number", "-5", "0xzz"]);
+        assert_eq!(results.len(), 5);
+        assert_eq!(results[0], Ok(SqlU256::from(123u64)));
+        assert_eq!(results[1], Ok(SqlU256::from(123u64)));
+
+        let failed: Vec<usize> = results
+            .iter()
+            .filter_map(|r| r.as_ref().err().map(|(i, _)| *i))
+            .collect();
+        assert_eq!(failed, vec![2, 3, 4]);
+
+        let (_, msg) = results[3].as_ref().unwrap_err();
+        assert_eq!(msg, "negative values are not allowed for unsigned type");
+    }
+
+    #[test]
+    fn test_as_ruint() {
+        let value = SqlU256::from(1024u64);
+        let raw = value.as_ruint();
+        assert_eq!(raw, value.inner());
+        assert!(raw.is_power_of_two());
+        assert_eq!(raw.bit_len(), 11);
+        assert_eq!(raw.reverse_bits().leading_zeros(), 10);
+    }
+
     #[test]
     fn test_from_str_parsing() {
         // Test decimal string parsing
@@ -413,6 +965,75 @@ mod tests {
         assert!(SqlU256::from_str("0x123xyz").is_err());
     }
 
+    #[test]
+    fn test_units_round_trip() {
+        let usdc = SqlU256::from_units("1234.56", 6).unwrap();
+        assert_eq!(usdc, SqlU256::from(1_234_560_000u64));
+        assert_eq!(usdc.to_units(6).unwrap(), "1234.560000");
+        assert_eq!(
+            SqlU256::from_units(&usdc.to_units(6).unwrap(), 6).unwrap(),
+            usdc
+        );
+
+        let eth = SqlU256::from_units("1.5", 18).unwrap();
+        assert_eq!(eth, SqlU256::from(1_500_000_000_000_000_000u128));
+        assert_eq!(eth.to_units(18).unwrap(), "1.500000000000000000");
+        assert_eq!(
+            SqlU256::from_units(&eth.to_units(18).unwrap(), 18).unwrap(),
+            eth
+        );
+
+        assert!(SqlU256::from_units("abc", 6).is_err());
+        assert!(SqlU256::from(1u64).to_units(100).is_err());
+    }
+
+    #[test]
+    fn test_binary_string() {
+        assert_eq!(SqlU256::ZERO.to_binary_string(), "0".repeat(256));
+
+        let five = SqlU256::from(5u64).to_binary_string();
+        assert_eq!(five.len(), 256);
+        assert!(five.ends_with("101"));
+        assert!(five[..253].bytes().all(|b| b == b'0'));
+
+        let value =
+            SqlU256::from_str("0xdeadbeef00000000000000000000000000000000000000000000000000000001")
+                .unwrap();
+        assert_eq!(
+            SqlU256::from_binary_string(&value.to_binary_string()).unwrap(),
+            value
+        );
+
+        assert!(SqlU256::from_binary_string("").is_err());
+        assert!(SqlU256::from_binary_string("0b101").is_err());
+        assert!(SqlU256::from_binary_string(&"1".repeat(257)).is_err());
+    }
+
+    #[test]
+    fn test_from_str_leading_plus() {
+        assert_eq!(SqlU256::from_str("+5").unwrap(), SqlU256::from(5u64));
+        assert!(SqlU256::from_str("++5").is_err());
+        assert!(SqlU256::from_str("+-5").is_err());
+        // Only decimal inputs accept the sign
+        assert!(SqlU256::from_str("+0x2a").is_err());
+    }
+
+    #[test]
+    fn test_from_str_rejects_negative() {
+        let err = SqlU256::from_str("-1").unwrap_err();
+        assert_eq!(err, ParseUintError::Negative);
+        assert_eq!(
+            err.to_string(),
+            "negative values are not allowed for unsigned type"
+        );
+
+        assert_eq!(SqlU256::from_str("1").unwrap(), SqlU256::from(1u64));
+        assert!(matches!(
+            SqlU256::from_str("abc"),
+            Err(ParseUintError::Invalid(_))
+        ));
+    }
+
     #[test]
     fn test_display_formatting() {
         let test_cases = [
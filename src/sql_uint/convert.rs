@@ -61,16 +61,85 @@ impl TryFrom<isize> for SqlU256 {
     }
 }
 
+impl From<[u8; 32]> for SqlU256 {
+    /// Interprets the array as a big-endian value.
+    fn from(bytes: [u8; 32]) -> Self {
+        SqlU256::from(U256::from_be_bytes(bytes))
+    }
+}
+
+impl TryFrom<&[u8]> for SqlU256 {
+    type Error = &'static str;
+
+    /// Interprets the slice as a big-endian value, left-padding slices shorter than 32 bytes.
+    fn try_from(bytes: &[u8]) -> Result<Self, Self::Error> {
+        if bytes.len() > 32 {
+            return Err("Byte slice longer than 32 bytes cannot be converted to SqlU256");
+        }
+        Ok(SqlU256::from_be_slice(bytes))
+    }
+}
+
+#[cfg(feature = "cosmwasm-compat")]
+#[cfg_attr(docsrs, doc(cfg(feature = "cosmwasm-compat")))]
+impl From<cosmwasm_std::Uint256> for SqlU256 {
+    /// Converts a CosmWasm `Uint256` via its big-endian byte representation.
+    fn from(value: cosmwasm_std::Uint256) -> Self {
+        SqlU256::from(value.to_be_bytes())
+    }
+}
+
+#[cfg(feature = "cosmwasm-compat")]
+#[cfg_attr(docsrs, doc(cfg(feature = "cosmwasm-compat")))]
+impl From<SqlU256> for cosmwasm_std::Uint256 {
+    /// Converts to a CosmWasm `Uint256` via the big-endian byte representation.
+    fn from(value: SqlU256) -> Self {
+        cosmwasm_std::Uint256::from_be_bytes(value.to_be_bytes())
+    }
+}
+
+/// Error returned when a `SqlU256` does not fit in a smaller integer type.
+///
+/// Carries the offending value so callers can log it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ConversionError {
+    /// The value that failed to convert, as `0x`-prefixed lowercase hex.
+    pub value_hex: String,
+    /// Name of the target type, e.g. `"u64"`.
+    pub target: &'static str,
+}
+
+impl ConversionError {
+    fn new(value: &SqlU256, target: &'static str) -> Self {
+        ConversionError {
+            value_hex: value.to_string(),
+            target,
+        }
+    }
+}
+
+impl std::fmt::Display for ConversionError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "SqlU256 value {} too large for {}",
+            self.value_hex, self.target
+        )
+    }
+}
+
+impl std::error::Error for ConversionError {}
+
 // Macro to reduce boilerplate for SqlU256 to smaller integer TryFrom conversions
 macro_rules! impl_try_from_sql_u256 {
     ($($t:ty),*) => {
         $(
             impl TryFrom<SqlU256> for $t {
-                type Error = &'static str;
+                type Error = ConversionError;
 
                 fn try_from(value: SqlU256) -> Result<Self, Self::Error> {
                     if value.0 > U256::from(<$t>::MAX) {
-                        Err(concat!("SqlU256 value too large for ", stringify!($t)))
+                        Err(ConversionError::new(&value, stringify!($t)))
                     } else {
                         Ok(value.0.to::<$t>())
                     }
@@ -84,11 +153,11 @@ macro_rules! impl_try_from_sql_u256 {
 impl_try_from_sql_u256!(u8, u16, u32, u64, u128);
 
 impl TryFrom<SqlU256> for usize {
-    type Error = &'static str;
+    type Error = ConversionError;
 
     fn try_from(value: SqlU256) -> Result<Self, Self::Error> {
         if value.0 > U256::from(u64::MAX) {
-            Err("SqlU256 value too large for usize")
+            Err(ConversionError::new(&value, "usize"))
         } else {
             Ok(value.0.to::<u64>() as usize)
         }
@@ -177,6 +246,45 @@ mod tests {
         assert_eq!(u128::try_from(very_large).unwrap(), u128::MAX);
     }
 
+    #[test]
+    fn test_conversion_error_detail() {
+        let err = u8::try_from(SqlU256::from(256u64)).unwrap_err();
+        assert_eq!(err.value_hex, "0x100");
+        assert_eq!(err.target, "u8");
+        assert_eq!(err.to_string(), "SqlU256 value 0x100 too large for u8");
+
+        let err = usize::try_from(SqlU256::from(u128::MAX)).unwrap_err();
+        assert_eq!(err.value_hex, "0xffffffffffffffffffffffffffffffff");
+        assert_eq!(err.target, "usize");
+
+        let err = SqlU256::from(u64::MAX).as_u32().unwrap_err();
+        assert_eq!(err.value_hex, "0xffffffffffffffff");
+        assert_eq!(err.target, "u32");
+    }
+
+    #[test]
+    fn test_from_byte_arrays() {
+        let mut arr = [0u8; 32];
+        arr[30] = 0x01;
+        arr[31] = 0x2a;
+        assert_eq!(SqlU256::from(arr), SqlU256::from(0x012au64));
+        assert_eq!(SqlU256::from([0xffu8; 32]), SqlU256::from(U256::MAX));
+
+        // A 20-byte slice is left-padded
+        let mut addr = [0u8; 20];
+        addr[0] = 0x80;
+        addr[19] = 0x01;
+        let value = SqlU256::try_from(&addr[..]).unwrap();
+        assert_eq!(
+            value,
+            (SqlU256::from(0x80u64) << 152u32) + SqlU256::from(1u64)
+        );
+        assert_eq!(SqlU256::try_from(&arr[..]).unwrap(), SqlU256::from(arr));
+        assert_eq!(SqlU256::try_from(&[][..]).unwrap(), SqlU256::ZERO);
+
+        assert!(SqlU256::try_from(&[0u8; 33][..]).is_err());
+    }
+
     #[test]
     fn test_conversion_chain() {
         // Test that we can chain conversions naturally
@@ -186,4 +294,28 @@ mod tests {
         let back: u64 = value.try_into().unwrap();
         assert_eq!(back, 42u64);
     }
+
+    #[cfg(feature = "cosmwasm-compat")]
+    #[test]
+    fn test_cosmwasm_uint256_round_trip() {
+        use cosmwasm_std::Uint256;
+
+        let cases = [
+            (Uint256::zero(), SqlU256::ZERO),
+            (Uint256::one(), SqlU256::from(1u64)),
+            (Uint256::from(u128::MAX), SqlU256::from(u128::MAX)),
+            (Uint256::MAX, SqlU256::from(U256::MAX)),
+        ];
+        for (cosm, sql) in cases {
+            assert_eq!(SqlU256::from(cosm), sql);
+            assert_eq!(Uint256::from(sql), cosm);
+        }
+
+        // Byte order is preserved, not just the numeric value of small inputs
+        let value = SqlU256::from(0x0102u64) << 200u32;
+        let cosm = Uint256::from(value);
+        assert_eq!(cosm.to_be_bytes(), value.to_be_bytes());
+        assert_eq!(cosm.to_string(), value.inner().to_string());
+        assert_eq!(SqlU256::from(cosm), value);
+    }
 }
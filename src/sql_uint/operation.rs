@@ -3,8 +3,11 @@
 //! This module provides arithmetic operations (+, -, *, /, %) and other mathematical
 //! operations for SqlU256, following Rust's standard library patterns.
 
-use crate::{SqlU256, U256};
+use crate::{ParseUintError, SqlU256, U256};
+use alloy::primitives::U512;
+use std::iter::{Product, Sum};
 use std::ops::{Add, BitAnd, BitOr, BitXor, Div, Mul, Not, Rem, Shl, Shr, Sub};
+use std::str::FromStr;
 
 /// Macro to implement binary arithmetic operations for all reference combinations
 macro_rules! impl_binary_op {
@@ -56,19 +59,116 @@ macro_rules! impl_unary_op {
     };
 }
 
-/// Macro to implement shift operations
+/// Macro to implement shift operations (including `<<=`/`>>=`) for each shift-amount type
 macro_rules! impl_shift_op {
-    ($trait:ident, $method:ident, $op:tt, $rhs:ty) => {
-        impl $trait<$rhs> for SqlU256 {
-            type Output = Self;
+    ($($rhs:ty),*) => {
+        $(
+            impl Shl<$rhs> for SqlU256 {
+                type Output = Self;
 
-            fn $method(self, rhs: $rhs) -> Self::Output {
-                SqlU256::from(self.0 $op rhs)
+                fn shl(self, rhs: $rhs) -> Self::Output {
+                    SqlU256::from(self.0 << rhs)
+                }
             }
-        }
+
+            impl Shr<$rhs> for SqlU256 {
+                type Output = Self;
+
+                fn shr(self, rhs: $rhs) -> Self::Output {
+                    SqlU256::from(self.0 >> rhs)
+                }
+            }
+
+            impl Shl<$rhs> for &SqlU256 {
+                type Output = SqlU256;
+
+                fn shl(self, rhs: $rhs) -> Self::Output {
+                    SqlU256::from(self.0 << rhs)
+                }
+            }
+
+            impl Shr<$rhs> for &SqlU256 {
+                type Output = SqlU256;
+
+                fn shr(self, rhs: $rhs) -> Self::Output {
+                    SqlU256::from(self.0 >> rhs)
+                }
+            }
+
+            impl ShlAssign<$rhs> for SqlU256 {
+                fn shl_assign(&mut self, rhs: $rhs) {
+                    self.0 <<= rhs;
+                }
+            }
+
+            impl ShrAssign<$rhs> for SqlU256 {
+                fn shr_assign(&mut self, rhs: $rhs) {
+                    self.0 >>= rhs;
+                }
+            }
+        )*
+    };
+}
+
+/// Macro to implement shift operations for signed right-hand sides.
+///
+/// Negative amounts panic rather than being cast to a huge unsigned shift.
+macro_rules! impl_signed_shift_op {
+    ($($rhs:ty),*) => {
+        $(
+            impl Shl<$rhs> for SqlU256 {
+                type Output = Self;
+
+                fn shl(self, rhs: $rhs) -> Self::Output {
+                    self << unsigned_shift(rhs)
+                }
+            }
+
+            impl Shr<$rhs> for SqlU256 {
+                type Output = Self;
+
+                fn shr(self, rhs: $rhs) -> Self::Output {
+                    self >> unsigned_shift(rhs)
+                }
+            }
+
+            impl Shl<$rhs> for &SqlU256 {
+                type Output = SqlU256;
+
+                fn shl(self, rhs: $rhs) -> Self::Output {
+                    self << unsigned_shift(rhs)
+                }
+            }
+
+            impl Shr<$rhs> for &SqlU256 {
+                type Output = SqlU256;
+
+                fn shr(self, rhs: $rhs) -> Self::Output {
+                    self >> unsigned_shift(rhs)
+                }
+            }
+
+            impl ShlAssign<$rhs> for SqlU256 {
+                fn shl_assign(&mut self, rhs: $rhs) {
+                    *self <<= unsigned_shift(rhs);
+                }
+            }
+
+            impl ShrAssign<$rhs> for SqlU256 {
+                fn shr_assign(&mut self, rhs: $rhs) {
+                    *self >>= unsigned_shift(rhs);
+                }
+            }
+        )*
     };
 }
 
+/// Converts a signed shift amount to `usize`, panicking if it is negative.
+fn unsigned_shift(rhs: impl TryInto<usize>) -> usize {
+    rhs.try_into()
+        .unwrap_or_else(|_| panic!("attempt to shift SqlU256 by a negative amount"))
+}
+
 /// Macro to implement binary assignment operations (e.g., +=, -=, etc.)
 macro_rules! impl_binary_assign_op {
     ($trait:ident, $method:ident, $op:tt) => {
@@ -85,51 +185,62 @@ macro_rules! impl_binary_assign_op {
     };
 }
 
-use std::ops::{AddAssign, DivAssign, MulAssign, RemAssign, SubAssign};
+use std::ops::{
+    AddAssign, BitAndAssign, BitOrAssign, BitXorAssign, DivAssign, MulAssign, RemAssign, ShlAssign,
+    ShrAssign, SubAssign,
+};
 impl_binary_assign_op!(AddAssign, add_assign, +);
 impl_binary_assign_op!(SubAssign, sub_assign, -);
 impl_binary_assign_op!(MulAssign, mul_assign, *);
 impl_binary_assign_op!(DivAssign, div_assign, /);
 impl_binary_assign_op!(RemAssign, rem_assign, %);
+impl_binary_assign_op!(BitAndAssign, bitand_assign, &);
+impl_binary_assign_op!(BitOrAssign, bitor_assign, |);
+impl_binary_assign_op!(BitXorAssign, bitxor_assign, ^);
 
-// Binary arithmetic operations
-impl_binary_op!(Add, add, +);
-impl_binary_op!(Sub, sub, -);
-impl_binary_op!(Mul, mul, *);
-impl_binary_op!(Div, div, /);
-impl_binary_op!(Rem, rem, %);
-
-// Bitwise operations (only value-to-value, no reference variants needed for these)
-impl BitAnd for SqlU256 {
-    type Output = Self;
-
-    fn bitand(self, rhs: Self) -> Self::Output {
-        SqlU256::from(self.0 & rhs.0)
+impl Sum for SqlU256 {
+    fn sum<I: Iterator<Item = SqlU256>>(iter: I) -> Self {
+        iter.fold(SqlU256::ZERO, |acc, x| acc + x)
     }
 }
 
-impl BitOr for SqlU256 {
-    type Output = Self;
-
-    fn bitor(self, rhs: Self) -> Self::Output {
-        SqlU256::from(self.0 | rhs.0)
+impl<'a> Sum<&'a SqlU256> for SqlU256 {
+    fn sum<I: Iterator<Item = &'a SqlU256>>(iter: I) -> Self {
+        iter.fold(SqlU256::ZERO, |acc, x| acc + x)
     }
 }
 
-impl BitXor for SqlU256 {
-    type Output = Self;
+impl Product for SqlU256 {
+    fn product<I: Iterator<Item = SqlU256>>(iter: I) -> Self {
+        iter.fold(SqlU256::from(1u64), |acc, x| acc * x)
+    }
+}
 
-    fn bitxor(self, rhs: Self) -> Self::Output {
-        SqlU256::from(self.0 ^ rhs.0)
+impl<'a> Product<&'a SqlU256> for SqlU256 {
+    fn product<I: Iterator<Item = &'a SqlU256>>(iter: I) -> Self {
+        iter.fold(SqlU256::from(1u64), |acc, x| acc * x)
     }
 }
 
+// Binary arithmetic operations
+impl_binary_op!(Add, add, +);
+impl_binary_op!(Sub, sub, -);
+impl_binary_op!(Mul, mul, *);
+impl_binary_op!(Div, div, /);
+impl_binary_op!(Rem, rem, %);
+
+// Bitwise operations
+impl_binary_op!(BitAnd, bitand, &);
+impl_binary_op!(BitOr, bitor, |);
+impl_binary_op!(BitXor, bitxor, ^);
+
 // Unary operations
 impl_unary_op!(Not, not, !);
 
-// Shift operations
-impl_shift_op!(Shl, shl, <<, usize);
-impl_shift_op!(Shr, shr, >>, usize);
+// Shift operations. `i32` is accepted so unsuffixed literals (`value << 8`) still infer;
+// a negative `i32` amount panics.
+impl_shift_op!(u8, u16, u32, usize);
+impl_signed_shift_op!(i32);
 
 // Additional mathematical operations
 impl SqlU256 {
@@ -143,6 +254,50 @@ impl SqlU256 {
         SqlU256::from(self.0.pow(U256::from(exp)))
     }
 
+    /// Checked exponentiation with a full-width exponent. Returns `None` if overflow occurred.
+    ///
+    /// # Examples
+    /// ```
+    /// use ethereum_mysql::SqlU256;
+    /// let two = SqlU256::from(2u64);
+    /// assert_eq!(two.checked_pow(SqlU256::from(10u64)), Some(SqlU256::from(1024u64)));
+    /// assert_eq!(two.checked_pow(SqlU256::from(256u64)), None);
+    /// ```
+    pub fn checked_pow(self, exp: Self) -> Option<Self> {
+        self.0.checked_pow(exp.0).map(SqlU256::from)
+    }
+
+    /// Returns `2^exp`, computed as `1 << exp`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `exp >= 256`. Use [`checked_pow2`](Self::checked_pow2) to handle this case.
+    pub fn pow2(exp: u32) -> Self {
+        Self::checked_pow2(exp).expect("SqlU256::pow2 exponent out of range")
+    }
+
+    /// Returns `2^exp`, or `None` if `exp >= 256`.
+    pub fn checked_pow2(exp: u32) -> Option<Self> {
+        SqlU256::from(1u64).checked_shl(exp as usize)
+    }
+
+    /// Returns the smallest power of two greater than or equal to `self` (1 for zero).
+    ///
+    /// # Panics
+    ///
+    /// Panics if the result exceeds `2^255`. Use
+    /// [`checked_next_power_of_two`](Self::checked_next_power_of_two) to handle this case.
+    pub fn next_power_of_two(&self) -> Self {
+        self.checked_next_power_of_two()
+            .expect("SqlU256::next_power_of_two overflow")
+    }
+
+    /// Returns the smallest power of two greater than or equal to `self`,
+    /// or `None` if it does not fit in 256 bits.
+    pub fn checked_next_power_of_two(&self) -> Option<Self> {
+        self.0.checked_next_power_of_two().map(SqlU256::from)
+    }
+
     /// Returns the greatest common divisor of two values
     pub fn gcd(self, other: Self) -> Self {
         let mut a = self.0;
@@ -167,6 +322,31 @@ impl SqlU256 {
         }
     }
 
+    /// Returns the integer square root, i.e. the largest `r` with `r * r <= self`.
+    ///
+    /// # Examples
+    /// ```
+    /// use ethereum_mysql::SqlU256;
+    /// assert_eq!(SqlU256::from(16u64).isqrt(), SqlU256::from(4u64));
+    /// assert_eq!(SqlU256::from(17u64).isqrt(), SqlU256::from(4u64));
+    /// ```
+    pub fn isqrt(self) -> Self {
+        let n = self.0;
+        if n < U256::from(2u64) {
+            return self;
+        }
+        // Newton's method from an initial guess of 2^ceil(bits/2), which is >= sqrt(n);
+        // the iterates decrease monotonically until they reach the floor root.
+        let mut x = U256::from(1u64) << n.bit_len().div_ceil(2);
+        loop {
+            let y = (x + n / x) >> 1;
+            if y >= x {
+                return SqlU256::from(x);
+            }
+            x = y;
+        }
+    }
+
     /// Checked addition. Returns `None` if overflow occurred.
     pub fn checked_add(self, rhs: Self) -> Option<Self> {
         self.0.checked_add(rhs.0).map(SqlU256::from)
@@ -191,6 +371,221 @@ impl SqlU256 {
         }
     }
 
+    /// Checked division with remainder. Returns `None` if `rhs == 0`.
+    ///
+    /// Returns `(quotient, remainder)` computed in a single step.
+    pub fn checked_div_rem(self, rhs: Self) -> Option<(Self, Self)> {
+        if rhs.0.is_zero() {
+            None
+        } else {
+            let (quotient, remainder) = self.0.div_rem(rhs.0);
+            Some((SqlU256::from(quotient), SqlU256::from(remainder)))
+        }
+    }
+
+    /// Parses `rhs` (decimal or `0x` hex) and adds it with [`checked_add`](Self::checked_add).
+    ///
+    /// Returns `Err` if `rhs` does not parse and `Ok(None)` on overflow.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ethereum_mysql::SqlU256;
+    ///
+    /// let total = SqlU256::from(40u64).checked_add_str("2").unwrap();
+    /// assert_eq!(total, Some(SqlU256::from(42u64)));
+    /// ```
+    pub fn checked_add_str(&self, rhs: &str) -> Result<Option<Self>, ParseUintError> {
+        let rhs = SqlU256::from_str(rhs)?;
+        Ok(self.checked_add(rhs))
+    }
+
+    /// Checked shift left. Returns `None` if `rhs >= 256`.
+    ///
+    /// Bits shifted out of the high end are discarded, as with `<<`.
+    pub fn checked_shl(self, rhs: usize) -> Option<Self> {
+        if rhs >= 256 {
+            None
+        } else {
+            Some(SqlU256::from(self.0 << rhs))
+        }
+    }
+
+    /// Checked shift right. Returns `None` if `rhs >= 256`.
+    pub fn checked_shr(self, rhs: usize) -> Option<Self> {
+        if rhs >= 256 {
+            None
+        } else {
+            Some(SqlU256::from(self.0 >> rhs))
+        }
+    }
+
+    /// Computes `self * rhs % modulus` without intermediate overflow.
+    ///
+    /// Returns zero if `modulus == 0`.
+    pub fn mul_mod(self, rhs: Self, modulus: Self) -> Self {
+        SqlU256::from(self.0.mul_mod(rhs.0, modulus.0))
+    }
+
+    /// Computes `self^exp % modulus` without intermediate overflow.
+    ///
+    /// Returns zero if `modulus` is 0 or 1.
+    ///
+    /// # Examples
+    /// ```
+    /// use ethereum_mysql::SqlU256;
+    /// let r = SqlU256::from(4u64).pow_mod(SqlU256::from(13u64), SqlU256::from(497u64));
+    /// assert_eq!(r, SqlU256::from(445u64));
+    /// ```
+    pub fn pow_mod(self, exp: Self, modulus: Self) -> Self {
+        SqlU256::from(self.0.pow_mod(exp.0, modulus.0))
+    }
+
+    /// Computes `self * numerator / denominator` using a 512-bit intermediate product.
+    ///
+    /// Returns `None` if `denominator == 0` or the result does not fit in 256 bits.
+    pub fn mul_div(self, numerator: Self, denominator: Self) -> Option<Self> {
+        if denominator.0.is_zero() {
+            return None;
+        }
+        let product: U512 = self.0.widening_mul(numerator.0);
+        let quotient = product / U512::from_limbs_slice(denominator.0.as_limbs());
+        U256::checked_from_limbs_slice(quotient.as_limbs()).map(SqlU256::from)
+    }
+
+    /// Computes the fixed-point ratio `numerator * 10^scale_decimals / denominator`.
+    ///
+    /// Uses [`mul_div`](Self::mul_div) so the intermediate product cannot overflow.
+    /// Returns `None` if `denominator == 0` or the result does not fit in 256 bits.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ethereum_mysql::SqlU256;
+    ///
+    /// // 3 / 2 at 18 decimals = 1.5e18
+    /// let price = SqlU256::ratio(SqlU256::from(3u64), SqlU256::from(2u64), 18).unwrap();
+    /// assert_eq!(price, SqlU256::from(1_500_000_000_000_000_000u64));
+    /// ```
+    pub fn ratio(numerator: Self, denominator: Self, scale_decimals: u8) -> Option<Self> {
+        let scale = U256::from(10u64).checked_pow(U256::from(scale_decimals))?;
+        numerator.mul_div(SqlU256::from(scale), denominator)
+    }
+
+    /// Multiplies by `10^decimals`, returning `None` on overflow.
+    pub fn scale_up(self, decimals: u8) -> Option<Self> {
+        if self.0.is_zero() {
+            return Some(self);
+        }
+        let scale = U256::from(10u64).checked_pow(U256::from(decimals))?;
+        self.0.checked_mul(scale).map(SqlU256::from)
+    }
+
+    /// Divides by `10^decimals`, returning `(quotient, remainder)`.
+    ///
+    /// If `10^decimals` does not fit in 256 bits the quotient is zero and the remainder is `self`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ethereum_mysql::SqlU256;
+    ///
+    /// let (whole, frac) = SqlU256::from(1_234_567u64).scale_down(6);
+    /// assert_eq!(whole, SqlU256::from(1u64));
+    /// assert_eq!(frac, SqlU256::from(234_567u64));
+    /// ```
+    pub fn scale_down(self, decimals: u8) -> (Self, Self) {
+        match U256::from(10u64).checked_pow(U256::from(decimals)) {
+            Some(scale) => {
+                let (quotient, remainder) = self.0.div_rem(scale);
+                (SqlU256::from(quotient), SqlU256::from(remainder))
+            }
+            None => (SqlU256::ZERO, self),
+        }
+    }
+
+    /// Computes the weighted average of `(value, weight)` pairs, rounded down.
+    ///
+    /// Like [`mul_div`](Self::mul_div), the weighted sum is accumulated in 512 bits, so
+    /// large values cannot overflow. Returns `None` if the total weight is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ethereum_mysql::SqlU256;
+    ///
+    /// let prices = [(SqlU256::from(100u64), 1), (SqlU256::from(200u64), 3)];
+    /// assert_eq!(SqlU256::weighted_average(&prices), Some(SqlU256::from(175u64)));
+    /// ```
+    pub fn weighted_average(values: &[(SqlU256, u64)]) -> Option<SqlU256> {
+        let mut sum = U512::ZERO;
+        let mut total_weight: u128 = 0;
+        for (value, weight) in values {
+            let product: U512 = value.0.widening_mul(U256::from(*weight));
+            sum += product;
+            total_weight += u128::from(*weight);
+        }
+        if total_weight == 0 {
+            return None;
+        }
+        let average = sum / U512::from(total_weight);
+        U256::checked_from_limbs_slice(average.as_limbs()).map(SqlU256::from)
+    }
+
+    /// Overflowing addition. Returns the wrapped result and whether overflow occurred.
+    pub fn overflowing_add(self, rhs: Self) -> (Self, bool) {
+        let (value, overflow) = self.0.overflowing_add(rhs.0);
+        (SqlU256::from(value), overflow)
+    }
+
+    /// Overflowing subtraction. Returns the wrapped result and whether underflow occurred.
+    pub fn overflowing_sub(self, rhs: Self) -> (Self, bool) {
+        let (value, overflow) = self.0.overflowing_sub(rhs.0);
+        (SqlU256::from(value), overflow)
+    }
+
+    /// Overflowing multiplication. Returns the wrapped result and whether overflow occurred.
+    pub fn overflowing_mul(self, rhs: Self) -> (Self, bool) {
+        let (value, overflow) = self.0.overflowing_mul(rhs.0);
+        (SqlU256::from(value), overflow)
+    }
+
+    /// Wrapping (modular 2^256) addition, as in the EVM.
+    pub fn wrapping_add(self, rhs: Self) -> Self {
+        SqlU256::from(self.0.wrapping_add(rhs.0))
+    }
+
+    /// Wrapping (modular 2^256) subtraction, as in the EVM.
+    pub fn wrapping_sub(self, rhs: Self) -> Self {
+        SqlU256::from(self.0.wrapping_sub(rhs.0))
+    }
+
+    /// Wrapping (modular 2^256) multiplication, as in the EVM.
+    pub fn wrapping_mul(self, rhs: Self) -> Self {
+        SqlU256::from(self.0.wrapping_mul(rhs.0))
+    }
+
+    /// Wrapping (modular 2^256) exponentiation, as in the EVM `EXP` opcode.
+    pub fn wrapping_pow(self, exp: Self) -> Self {
+        SqlU256::from(self.0.wrapping_pow(exp.0))
+    }
+
+    /// Adds `price * elapsed` to a cumulative price accumulator, wrapping on overflow.
+    ///
+    /// This mirrors Uniswap V2's `price0CumulativeLast` update, where only differences
+    /// between two snapshots are meaningful, so the wrap-around is harmless as long as
+    /// the TWAP is computed as `later.wrapping_sub(earlier) / elapsed`.
+    ///
+    /// # Examples
+    /// ```
+    /// use ethereum_mysql::SqlU256;
+    /// let acc = SqlU256::from(100u64).wrapping_accumulate(SqlU256::from(5u64), 12);
+    /// assert_eq!(acc, SqlU256::from(160u64));
+    /// ```
+    pub fn wrapping_accumulate(self, price: SqlU256, elapsed: u64) -> Self {
+        self.wrapping_add(price.wrapping_mul(SqlU256::from(elapsed)))
+    }
+
     /// Saturating addition. Clamps the result to `U256::MAX` if overflow occurred.
     pub fn saturating_add(self, rhs: Self) -> Self {
         SqlU256::from(self.0.saturating_add(rhs.0))
@@ -211,6 +606,33 @@ impl SqlU256 {
         self.0.is_zero()
     }
 
+    /// Returns the number of significant bits (0 for zero).
+    ///
+    /// # Examples
+    /// ```
+    /// use ethereum_mysql::SqlU256;
+    /// assert_eq!(SqlU256::from(0xffu64).bit_len(), 8);
+    /// assert_eq!(SqlU256::ZERO.bit_len(), 0);
+    /// ```
+    pub fn bit_len(&self) -> usize {
+        self.0.bit_len()
+    }
+
+    /// Returns the number of leading zero bits (256 for zero).
+    pub fn leading_zeros(&self) -> usize {
+        self.0.leading_zeros()
+    }
+
+    /// Returns the number of trailing zero bits (256 for zero).
+    pub fn trailing_zeros(&self) -> usize {
+        self.0.trailing_zeros()
+    }
+
+    /// Returns the number of set bits.
+    pub fn count_ones(&self) -> usize {
+        self.0.count_ones()
+    }
+
     /// Returns the minimum of two values
     pub fn min(self, other: Self) -> Self {
         if self.0 < other.0 {
@@ -267,6 +689,38 @@ mod tests {
         assert_eq!(!SqlU256::from(0u64), SqlU256::from(!U256::ZERO));
     }
 
+    #[test]
+    #[allow(clippy::op_ref)]
+    fn test_bitwise_with_references() {
+        let a = SqlU256::from(0b1100u64);
+        let b = SqlU256::from(0b1010u64);
+
+        assert_eq!(&a & &b, SqlU256::from(0b1000u64));
+        assert_eq!(a & &b, SqlU256::from(0b1000u64));
+        assert_eq!(&a & b, SqlU256::from(0b1000u64));
+        assert_eq!(&a | &b, SqlU256::from(0b1110u64));
+        assert_eq!(a | &b, SqlU256::from(0b1110u64));
+        assert_eq!(&a | b, SqlU256::from(0b1110u64));
+        assert_eq!(&a ^ &b, SqlU256::from(0b0110u64));
+        assert_eq!(a ^ &b, SqlU256::from(0b0110u64));
+        assert_eq!(&a ^ b, SqlU256::from(0b0110u64));
+    }
+
+    #[test]
+    fn test_bitwise_assign() {
+        let mask = SqlU256::from(0b1010u64);
+
+        let mut x = SqlU256::from(0b1100u64);
+        x &= mask;
+        assert_eq!(x, SqlU256::from(0b1000u64));
+        x |= &mask;
+        assert_eq!(x, SqlU256::from(0b1010u64));
+        x ^= mask;
+        assert_eq!(x, SqlU256::ZERO);
+        x ^= &SqlU256::from(U256::MAX);
+        assert_eq!(x, SqlU256::from(U256::MAX));
+    }
+
     #[test]
     fn test_shift_operations() {
         let a = SqlU256::from(8u64);
@@ -277,6 +731,58 @@ mod tests {
         assert_eq!(a >> 2, SqlU256::from(2u64));
     }
 
+    #[test]
+    fn test_shift_rhs_types() {
+        let one = SqlU256::from(1u64);
+        let high = SqlU256::pow2(255);
+
+        assert_eq!(one << 255u32, high);
+        assert_eq!(one << 255u8, high);
+        assert_eq!(one << 255u16, high);
+        assert_eq!(one << 255usize, high);
+        assert_eq!(high >> 255u32, one);
+        assert_eq!(high >> 255u8, one);
+        assert_eq!(high >> 255u16, one);
+        assert_eq!(high >> 255usize, one);
+
+        // Reference left-hand side
+        assert_eq!(&one << 4u32, SqlU256::from(16u64));
+        assert_eq!(&high >> 254u16, SqlU256::from(2u64));
+
+        // Shifting by the full width clears the value
+        assert_eq!(one << 256u32, SqlU256::ZERO);
+        assert_eq!(high >> 256usize, SqlU256::ZERO);
+    }
+
+    #[test]
+    fn test_shift_assign() {
+        let mut x = SqlU256::from(1u64);
+        x <<= 4;
+        assert_eq!(x, SqlU256::from(16u64));
+        x <<= 4u8;
+        assert_eq!(x, SqlU256::from(256u64));
+        x >>= 2u16;
+        assert_eq!(x, SqlU256::from(64u64));
+        x >>= 6u32;
+        assert_eq!(x, SqlU256::from(1u64));
+        x <<= 255usize;
+        assert_eq!(x, SqlU256::pow2(255));
+    }
+
+    #[test]
+    #[should_panic(expected = "negative amount")]
+    fn test_shl_negative_i32_panics() {
+        let shift: i32 = -1;
+        let _ = SqlU256::from(1u64) << shift;
+    }
+
+    #[test]
+    #[should_panic(expected = "negative amount")]
+    fn test_shr_assign_negative_i32_panics() {
+        let mut x = SqlU256::from(1u64);
+        x >>= -8i32;
+    }
+
     #[test]
     fn test_mathematical_operations() {
         let a = SqlU256::from(5u64);
@@ -294,6 +800,18 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_pow2() {
+        assert_eq!(SqlU256::pow2(0), SqlU256::from(1u64));
+        assert_eq!(SqlU256::pow2(8), SqlU256::from(256u64));
+        assert_eq!(SqlU256::pow2(255), SqlU256::from(U256::from(1u64) << 255));
+        assert_eq!(SqlU256::pow2(64), SqlU256::from(2u64).pow(64));
+
+        assert_eq!(SqlU256::checked_pow2(255), Some(SqlU256::pow2(255)));
+        assert_eq!(SqlU256::checked_pow2(256), None);
+        assert!(std::panic::catch_unwind(|| SqlU256::pow2(256)).is_err());
+    }
+
     #[test]
     fn test_checked_operations() {
         let a = SqlU256::from(100u64);
@@ -310,6 +828,128 @@ mod tests {
         assert_eq!(b.checked_sub(a), None);
     }
 
+    #[test]
+    fn test_checked_div_rem() {
+        let a = SqlU256::from(100u64);
+
+        assert_eq!(
+            a.checked_div_rem(SqlU256::from(25u64)),
+            Some((SqlU256::from(4u64), SqlU256::ZERO))
+        );
+        assert_eq!(
+            a.checked_div_rem(SqlU256::from(30u64)),
+            Some((SqlU256::from(3u64), SqlU256::from(10u64)))
+        );
+        assert_eq!(a.checked_div_rem(SqlU256::ZERO), None);
+    }
+
+    #[test]
+    fn test_checked_shifts() {
+        let one = SqlU256::from(1u64);
+        let high_bit = SqlU256::from(U256::from(1u64) << 255);
+
+        assert_eq!(one.checked_shl(0), Some(one));
+        assert_eq!(one.checked_shl(255), Some(high_bit));
+        assert_eq!(one.checked_shl(256), None);
+
+        assert_eq!(high_bit.checked_shr(0), Some(high_bit));
+        assert_eq!(high_bit.checked_shr(255), Some(one));
+        assert_eq!(high_bit.checked_shr(256), None);
+    }
+
+    #[test]
+    fn test_checked_add_str() {
+        let a = SqlU256::from(100u64);
+        assert_eq!(a.checked_add_str("23"), Ok(Some(SqlU256::from(123u64))));
+        assert_eq!(a.checked_add_str("0x10"), Ok(Some(SqlU256::from(116u64))));
+
+        let max = SqlU256::from(U256::MAX);
+        assert_eq!(max.checked_add_str("1"), Ok(None));
+
+        assert!(a.checked_add_str("one").is_err());
+        assert_eq!(a.checked_add_str("-1"), Err(ParseUintError::Negative));
+    }
+
+    #[test]
+    fn test_mul_div() {
+        let a = SqlU256::from(100u64);
+        assert_eq!(
+            a.mul_div(SqlU256::from(3u64), SqlU256::from(4u64)),
+            Some(SqlU256::from(75u64))
+        );
+        assert_eq!(a.mul_div(SqlU256::from(3u64), SqlU256::ZERO), None);
+
+        // Intermediate product exceeds 256 bits but the result fits
+        let max = SqlU256::from(U256::MAX);
+        let one = SqlU256::from(10u64).pow(18);
+        assert_eq!(max.mul_div(one, one), Some(max));
+        // Result itself overflows
+        assert_eq!(max.mul_div(SqlU256::from(2u64), SqlU256::from(1u64)), None);
+    }
+
+    #[test]
+    fn test_scale_up_and_down() {
+        let one = SqlU256::from(1u64);
+        assert_eq!(one.scale_up(18), Some(SqlU256::from(10u64).pow(18)));
+        assert_eq!(SqlU256::ZERO.scale_up(255), Some(SqlU256::ZERO));
+        // 10^78 does not fit in 256 bits
+        assert_eq!(one.scale_up(78), None);
+        // 2 * 10^77 overflows even though 10^77 fits
+        assert_eq!(SqlU256::from(2u64).scale_up(77), None);
+
+        let amount = SqlU256::from(1_500_000u64);
+        assert_eq!(
+            amount.scale_down(6),
+            (SqlU256::from(1u64), SqlU256::from(500_000u64))
+        );
+        assert_eq!(amount.scale_down(0), (amount, SqlU256::ZERO));
+        assert_eq!(amount.scale_down(100), (SqlU256::ZERO, amount));
+    }
+
+    #[test]
+    fn test_weighted_average() {
+        let values = [
+            (SqlU256::from(100u64), 1),
+            (SqlU256::from(200u64), 3),
+            (SqlU256::from(400u64), 0),
+        ];
+        assert_eq!(
+            SqlU256::weighted_average(&values),
+            Some(SqlU256::from(175u64))
+        );
+
+        // Intermediate products exceed 256 bits
+        let max = SqlU256::from(U256::MAX);
+        assert_eq!(
+            SqlU256::weighted_average(&[(max, u64::MAX), (max, u64::MAX)]),
+            Some(max)
+        );
+
+        let zero_weights = [(SqlU256::from(1u64), 0), (SqlU256::from(2u64), 0)];
+        assert_eq!(SqlU256::weighted_average(&zero_weights), None);
+        assert_eq!(SqlU256::weighted_average(&[]), None);
+    }
+
+    #[test]
+    fn test_ratio() {
+        // 3000 DAI (18 decimals) for 1.5 ETH (18 decimals): 2000 DAI per ETH at 18 decimals
+        let one = SqlU256::from(10u64).pow(18);
+        let dai = one * 3000u64;
+        let eth = one + one / 2u64;
+        let price = SqlU256::ratio(dai, eth, 18).unwrap();
+        assert_eq!(price, one * 2000u64);
+
+        assert_eq!(
+            SqlU256::ratio(SqlU256::from(1u64), SqlU256::from(3u64), 18),
+            Some(SqlU256::from(333_333_333_333_333_333u64))
+        );
+        assert_eq!(SqlU256::ratio(SqlU256::from(1u64), SqlU256::ZERO, 18), None);
+
+        // Large numerator whose scaled product exceeds 256 bits
+        let half_max = SqlU256::from(U256::MAX / U256::from(2u64));
+        assert_eq!(SqlU256::ratio(half_max, one, 18), Some(half_max));
+    }
+
     #[test]
     fn test_saturating_operations() {
         let a = SqlU256::from(100u64);
@@ -320,6 +960,92 @@ mod tests {
         assert_eq!(a.saturating_mul(b), SqlU256::from(15000u64));
     }
 
+    #[test]
+    fn test_sum_and_product() {
+        let values = vec![
+            SqlU256::from(2u64),
+            SqlU256::from(3u64),
+            SqlU256::from(7u64),
+        ];
+        assert_eq!(values.iter().sum::<SqlU256>(), SqlU256::from(12u64));
+        assert_eq!(values.iter().product::<SqlU256>(), SqlU256::from(42u64));
+        assert_eq!(
+            values.clone().into_iter().sum::<SqlU256>(),
+            SqlU256::from(12u64)
+        );
+        assert_eq!(
+            values.into_iter().product::<SqlU256>(),
+            SqlU256::from(42u64)
+        );
+
+        let empty: Vec<SqlU256> = Vec::new();
+        assert_eq!(empty.iter().sum::<SqlU256>(), SqlU256::ZERO);
+        assert_eq!(empty.iter().product::<SqlU256>(), SqlU256::from(1u64));
+
+        // Mixed owned/borrowed sources, e.g. amounts pulled from rows
+        let owned = SqlU256::from(5u64);
+        let borrowed = [SqlU256::from(10u64), SqlU256::from(20u64)];
+        let total: SqlU256 = std::iter::once(owned).chain(borrowed.iter().copied()).sum();
+        assert_eq!(total, SqlU256::from(35u64));
+        let total_ref: SqlU256 = std::iter::once(&owned).chain(borrowed.iter()).sum();
+        assert_eq!(total_ref, total);
+    }
+
+    #[test]
+    fn test_overflowing_operations() {
+        let max = SqlU256::from(U256::MAX);
+        let one = SqlU256::from(1u64);
+        let two = SqlU256::from(2u64);
+
+        assert_eq!(max.overflowing_add(SqlU256::ZERO), (max, false));
+        assert_eq!(max.overflowing_add(one), (SqlU256::ZERO, true));
+        assert_eq!(one.overflowing_sub(one), (SqlU256::ZERO, false));
+        assert_eq!(SqlU256::ZERO.overflowing_sub(one), (max, true));
+        assert_eq!(max.overflowing_mul(one), (max, false));
+        assert_eq!(max.overflowing_mul(two), (max - one, true));
+        assert_eq!(
+            SqlU256::pow2(128).overflowing_mul(SqlU256::pow2(127)),
+            (SqlU256::pow2(255), false)
+        );
+        assert_eq!(
+            SqlU256::pow2(128).overflowing_mul(SqlU256::pow2(128)),
+            (SqlU256::ZERO, true)
+        );
+    }
+
+    #[test]
+    fn test_wrapping_operations() {
+        let max = SqlU256::from(U256::MAX);
+        let one = SqlU256::from(1u64);
+        let two = SqlU256::from(2u64);
+
+        assert_eq!(max.wrapping_add(one), SqlU256::ZERO);
+        assert_eq!(SqlU256::ZERO.wrapping_sub(one), max);
+        assert_eq!(max.wrapping_mul(two), max - one);
+        assert_eq!(two.wrapping_pow(SqlU256::from(256u64)), SqlU256::ZERO);
+        assert_eq!(two.wrapping_pow(SqlU256::from(255u64)), SqlU256::pow2(255));
+
+        // No wrap away from the boundary
+        assert_eq!(one.wrapping_add(two), SqlU256::from(3u64));
+        assert_eq!(two.wrapping_sub(one), one);
+    }
+
+    #[test]
+    fn test_wrapping_accumulate() {
+        let max = SqlU256::from(U256::MAX);
+        let price = SqlU256::from(7u64);
+
+        // Wraps past U256::MAX back to the low values
+        let before = max - SqlU256::from(9u64);
+        let after = before.wrapping_accumulate(price, 3);
+        assert_eq!(after, SqlU256::from(11u64));
+
+        // The TWAP over the window is still recoverable from the wrapped snapshots
+        assert_eq!(after.wrapping_sub(before) / SqlU256::from(3u64), price);
+
+        assert_eq!(max.wrapping_accumulate(price, 0), max);
+    }
+
     #[test]
     fn test_utility_functions() {
         let a = SqlU256::from(100u64);
@@ -332,6 +1058,102 @@ mod tests {
         assert_eq!(a.max(b), a);
     }
 
+    #[test]
+    fn test_next_power_of_two() {
+        let n = |v: u64| SqlU256::from(v);
+
+        assert_eq!(SqlU256::ZERO.next_power_of_two(), n(1));
+        assert_eq!(n(1).next_power_of_two(), n(1));
+        assert_eq!(n(5).next_power_of_two(), n(8));
+        assert_eq!(n(1024).next_power_of_two(), n(1024));
+        assert_eq!(n(1025).next_power_of_two(), n(2048));
+
+        let top = SqlU256::pow2(255);
+        assert_eq!(top.next_power_of_two(), top);
+        assert_eq!((top - n(1)).checked_next_power_of_two(), Some(top));
+        assert_eq!((top + n(1)).checked_next_power_of_two(), None);
+        assert_eq!(SqlU256::from(U256::MAX).checked_next_power_of_two(), None);
+
+        let result = std::panic::catch_unwind(|| (top + n(1)).next_power_of_two());
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_checked_pow() {
+        let n = |v: u64| SqlU256::from(v);
+
+        assert_eq!(n(2).checked_pow(n(256)), None);
+        assert_eq!(n(2).checked_pow(n(255)), Some(SqlU256::pow2(255)));
+        assert_eq!(n(3).checked_pow(n(4)), Some(n(81)));
+        assert_eq!(n(10).checked_pow(n(18)), Some(n(1_000_000_000_000_000_000)));
+        assert_eq!(n(7).checked_pow(SqlU256::ZERO), Some(n(1)));
+
+        // Exponents beyond usize range are fine for 0 and 1 bases
+        let huge = SqlU256::from(U256::MAX);
+        assert_eq!(n(1).checked_pow(huge), Some(n(1)));
+        assert_eq!(SqlU256::ZERO.checked_pow(huge), Some(SqlU256::ZERO));
+        assert_eq!(n(2).checked_pow(huge), None);
+
+        // Agrees with the usize variant when it does not overflow
+        assert_eq!(n(5).checked_pow(n(20)), Some(n(5).pow(20)));
+    }
+
+    #[test]
+    fn test_modular_arithmetic() {
+        let n = |v: u64| SqlU256::from(v);
+        let max = SqlU256::from(U256::MAX);
+
+        assert_eq!(n(4).pow_mod(n(13), n(497)), n(445));
+        assert_eq!(n(3).pow_mod(n(200), n(1000)), n(1));
+        assert_eq!(n(7).pow_mod(SqlU256::ZERO, n(13)), n(1));
+        assert_eq!(n(7).mul_mod(n(8), n(10)), n(6));
+
+        // (2^256 - 1)^2 mod (2^256 - 2) == 1, which would overflow a plain multiply.
+        let m = max - n(1);
+        assert_eq!(max.mul_mod(max, m), n(1));
+        assert_eq!(max.pow_mod(n(2), m), n(1));
+
+        // Fermat: a^(p-1) == 1 mod p for the secp256k1 field prime
+        let p =
+            SqlU256::from_str("0xfffffffffffffffffffffffffffffffffffffffffffffffffffffffefffffc2f")
+                .unwrap();
+        assert_eq!(n(12345).pow_mod(p - n(1), p), n(1));
+
+        // modulus == 1 always yields zero
+        assert_eq!(n(5).pow_mod(n(3), n(1)), SqlU256::ZERO);
+        assert_eq!(n(5).mul_mod(n(3), n(1)), SqlU256::ZERO);
+
+        // modulus == 0 is documented to yield zero
+        assert_eq!(n(5).pow_mod(n(3), SqlU256::ZERO), SqlU256::ZERO);
+        assert_eq!(n(5).mul_mod(n(3), SqlU256::ZERO), SqlU256::ZERO);
+    }
+
+    #[test]
+    fn test_bit_introspection() {
+        assert_eq!(SqlU256::from(0xffu64).bit_len(), 8);
+        assert_eq!(SqlU256::ZERO.bit_len(), 0);
+        assert_eq!(SqlU256::ZERO.leading_zeros(), 256);
+        assert_eq!(SqlU256::ZERO.count_ones(), 0);
+        assert_eq!(SqlU256::from(U256::MAX).count_ones(), 256);
+        assert_eq!(SqlU256::from(0x50u64).trailing_zeros(), 4);
+
+        for value in [
+            U256::ZERO,
+            U256::from(1u64),
+            U256::from(0x8000u64),
+            U256::from(u128::MAX),
+            U256::MAX - U256::from(1u64),
+            U256::MAX,
+        ] {
+            let sql = SqlU256::from(value);
+            assert_eq!(sql.bit_len(), value.bit_len());
+            assert_eq!(sql.leading_zeros(), value.leading_zeros());
+            assert_eq!(sql.trailing_zeros(), value.trailing_zeros());
+            assert_eq!(sql.count_ones(), value.count_ones());
+            assert_eq!(sql.bit_len() + sql.leading_zeros(), 256);
+        }
+    }
+
     #[test]
     fn test_division_by_zero_panics() {
         let a = SqlU256::from(100u64);
@@ -354,6 +1176,31 @@ mod tests {
         assert_eq!(zero.gcd(zero), zero);
     }
 
+    #[test]
+    fn test_isqrt() {
+        assert_eq!(SqlU256::ZERO.isqrt(), SqlU256::ZERO);
+        assert_eq!(SqlU256::from(1u64).isqrt(), SqlU256::from(1u64));
+
+        for n in 2u64..1000 {
+            let r = SqlU256::from(n).isqrt();
+            let expected = (n as f64).sqrt() as u64;
+            assert_eq!(r, SqlU256::from(expected), "isqrt({})", n);
+        }
+
+        // Perfect square and its neighbours
+        let root = SqlU256::from(u128::MAX);
+        let square = root * root;
+        assert_eq!(square.isqrt(), root);
+        assert_eq!(
+            (square - SqlU256::from(1u64)).isqrt(),
+            root - SqlU256::from(1u64)
+        );
+        assert_eq!((square + SqlU256::from(1u64)).isqrt(), root);
+
+        // floor(sqrt(2^256 - 1)) == 2^128 - 1
+        assert_eq!(SqlU256::from(U256::MAX).isqrt(), root);
+    }
+
     #[test]
     fn test_lcm_edge_cases() {
         let zero = SqlU256::ZERO;
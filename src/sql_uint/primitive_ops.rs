@@ -5,7 +5,7 @@
 //! This eliminates the need for explicit conversions like `value * SqlU256::from(2)`.
 
 use super::{SqlU256, U256};
-use std::ops::{Add, Div, Mul, Rem, Sub};
+use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Rem, RemAssign, Sub, SubAssign};
 
 /// Macro to implement arithmetic operations between SqlU256 and primitive types in both directions
 macro_rules! impl_primitive_ops {
@@ -225,6 +225,35 @@ impl_primitive_ops!(u64);
 impl_primitive_ops!(u128);
 impl_primitive_ops!(usize);
 
+/// Macro to implement compound assignment (e.g., `value += 2`) with primitive right-hand sides,
+/// delegating to the binary operators so overflow behaves identically.
+macro_rules! impl_primitive_assign_ops {
+    ($($prim_type:ty),*) => {
+        $(
+            impl_primitive_assign_ops!(@op $prim_type, AddAssign, add_assign, +);
+            impl_primitive_assign_ops!(@op $prim_type, SubAssign, sub_assign, -);
+            impl_primitive_assign_ops!(@op $prim_type, MulAssign, mul_assign, *);
+            impl_primitive_assign_ops!(@op $prim_type, DivAssign, div_assign, /);
+            impl_primitive_assign_ops!(@op $prim_type, RemAssign, rem_assign, %);
+        )*
+    };
+    (@op $prim_type:ty, $trait:ident, $method:ident, $op:tt) => {
+        impl $trait<$prim_type> for SqlU256 {
+            fn $method(&mut self, rhs: $prim_type) {
+                *self = *self $op rhs;
+            }
+        }
+
+        impl $trait<&$prim_type> for SqlU256 {
+            fn $method(&mut self, rhs: &$prim_type) {
+                *self = *self $op *rhs;
+            }
+        }
+    };
+}
+
+impl_primitive_assign_ops!(u8, u16, u32, u64, u128, usize);
+
 // For signed integers, we only implement the safe operations
 // (addition, multiplication) to avoid potential underflow issues
 macro_rules! impl_signed_ops {
@@ -372,6 +401,35 @@ mod tests {
         assert_eq!(&value * &multiplier, SqlU256::from(200u64));
     }
 
+    #[test]
+    fn test_primitive_assign_operations() {
+        let mut total = SqlU256::ZERO;
+        for amount in [10u64, 20, 30] {
+            total += amount;
+        }
+        assert_eq!(total, SqlU256::from(60u64));
+
+        total -= 5u8;
+        total *= &2u32;
+        total /= 5usize;
+        total %= 7u128;
+        assert_eq!(
+            total,
+            ((SqlU256::from(60u64) - 5u8) * 2u32 / 5usize) % 7u128
+        );
+
+        let mut value = SqlU256::from(100u64);
+        value += &1u16;
+        assert_eq!(value, SqlU256::from(100u64) + 1u16);
+    }
+
+    #[test]
+    fn test_primitive_assign_overflow_matches_binary_op() {
+        let mut value = SqlU256::from(1u64);
+        value -= 2u64;
+        assert_eq!(value, SqlU256::from(1u64) - 2u64);
+    }
+
     #[test]
     fn test_signed_positive_operations() {
         let value = SqlU256::from(100u64);
@@ -11,6 +11,13 @@
 //! - When reading from the database, both `0x`-prefixed hex strings and pure decimal strings are supported.
 //! - For best compatibility and predictable sorting/comparison, always store U256 as hex strings in the database.
 //! - If you store decimal strings, reading is supported, but database-level comparison/sorting may not match Rust-side logic.
+//! - To write values zero-padded to the full width (`0x` + 64 hex digits for U256), so `ORDER BY` and
+//!   `WHERE amount > ?` on the string column match numeric order, bind [`PaddedU256`](crate::PaddedU256) instead.
+//!
+//! **I256** values are written as signed decimal (e.g. `-42`) and read as signed decimal or
+//! sign-magnitude hex (`-0x2a`).
+//!
+//! **FixedBytes** values are written as `0x`-prefixed lowercase hex and read with or without the prefix.
 //!
 #![cfg_attr(docsrs, doc(cfg(feature = "sqlx")))]
 
@@ -39,6 +46,10 @@ pub enum DecodeError {
     #[error("Uint decode error: source {0}")]
     UintDecodeError(String),
 
+    /// Returned when the database value is not a valid signed integer string.
+    #[error("Int decode error: source {0}")]
+    IntDecodeError(String),
+
     /// Returned when the database value is not a valid FixedBytes string.
     #[error("FixedBytes decode error: source {0}")]
     FixedBytesDecodeError(String),
@@ -48,7 +59,7 @@ pub enum DecodeError {
     BytesDecodeError(String),
 }
 
-use crate::{SqlAddress, SqlBytes, SqlFixedBytes, SqlUint};
+use crate::{SqlAddress, SqlBloom, SqlBytes, SqlFixedBytes, SqlI256, SqlSignature, SqlUint};
 
 // for SqlAddress
 impl<DB: Database> Type<DB> for SqlAddress
@@ -72,7 +83,7 @@ where
         &self,
         buf: &mut <DB as Database>::ArgumentBuffer<'a>,
     ) -> Result<IsNull, BoxDynError> {
-        self.to_string().to_lowercase().encode_by_ref(buf)
+        self.to_storage_string().encode_by_ref(buf)
     }
 }
 
@@ -125,8 +136,8 @@ where
     }
 }
 
-/// for SqlFixedBytes<32>
-impl<DB: Database> Type<DB> for SqlFixedBytes<32>
+// for SqlI256
+impl<DB: Database> Type<DB> for SqlI256
 where
     String: Type<DB>,
 {
@@ -138,7 +149,43 @@ where
         <String as Type<DB>>::compatible(ty)
     }
 }
-impl<'a, DB: Database> Encode<'a, DB> for SqlFixedBytes<32>
+
+impl<'a, DB: Database> Encode<'a, DB> for SqlI256
+where
+    String: Encode<'a, DB>,
+{
+    fn encode_by_ref(
+        &self,
+        buf: &mut <DB as Database>::ArgumentBuffer<'a>,
+    ) -> Result<IsNull, BoxDynError> {
+        self.to_string().encode_by_ref(buf)
+    }
+}
+
+impl<'a, DB: Database> Decode<'a, DB> for SqlI256
+where
+    String: Decode<'a, DB>,
+{
+    fn decode(value: <DB as Database>::ValueRef<'a>) -> Result<Self, BoxDynError> {
+        let s = String::decode(value)?;
+        SqlI256::from_str(&s).map_err(|_| DecodeError::IntDecodeError(s.to_string()).into())
+    }
+}
+
+// for SqlFixedBytes<BYTES>
+impl<const BYTES: usize, DB: Database> Type<DB> for SqlFixedBytes<BYTES>
+where
+    String: Type<DB>,
+{
+    fn type_info() -> DB::TypeInfo {
+        <String as Type<DB>>::type_info()
+    }
+
+    fn compatible(ty: &DB::TypeInfo) -> bool {
+        <String as Type<DB>>::compatible(ty)
+    }
+}
+impl<'a, const BYTES: usize, DB: Database> Encode<'a, DB> for SqlFixedBytes<BYTES>
 where
     String: Encode<'a, DB>,
 {
@@ -149,13 +196,190 @@ where
         self.to_string().to_lowercase().encode_by_ref(buf)
     }
 }
-impl<'a, DB: Database> Decode<'a, DB> for SqlFixedBytes<32>
+impl<'a, const BYTES: usize, DB: Database> Decode<'a, DB> for SqlFixedBytes<BYTES>
 where
     String: Decode<'a, DB>,
 {
     fn decode(value: <DB as Database>::ValueRef<'a>) -> Result<Self, BoxDynError> {
         let s = String::decode(value)?;
-        SqlFixedBytes::<32>::from_str(&s).map_err(|_| DecodeError::FixedBytesDecodeError(s).into())
+        Ok(parse_fixed_bytes(s)?)
+    }
+}
+
+// PostgreSQL arrays: all wrappers are stored as text, so they bind as `text[]`
+// (e.g. `INSERT ... SELECT * FROM UNNEST($1::text[])` with a `Vec<SqlU256>`).
+#[cfg(feature = "postgres")]
+mod postgres_array {
+    use super::*;
+    use sqlx_postgres::{PgHasArrayType, PgTypeInfo};
+
+    impl PgHasArrayType for SqlAddress {
+        fn array_type_info() -> PgTypeInfo {
+            <String as PgHasArrayType>::array_type_info()
+        }
+
+        fn array_compatible(ty: &PgTypeInfo) -> bool {
+            <String as PgHasArrayType>::array_compatible(ty)
+        }
+    }
+
+    impl<const BITS: usize, const LIMBS: usize> PgHasArrayType for SqlUint<BITS, LIMBS> {
+        fn array_type_info() -> PgTypeInfo {
+            <String as PgHasArrayType>::array_type_info()
+        }
+
+        fn array_compatible(ty: &PgTypeInfo) -> bool {
+            <String as PgHasArrayType>::array_compatible(ty)
+        }
+    }
+
+    impl<const BITS: usize, const LIMBS: usize> PgHasArrayType for crate::SqlUintPadded<BITS, LIMBS> {
+        fn array_type_info() -> PgTypeInfo {
+            <String as PgHasArrayType>::array_type_info()
+        }
+
+        fn array_compatible(ty: &PgTypeInfo) -> bool {
+            <String as PgHasArrayType>::array_compatible(ty)
+        }
+    }
+
+    impl PgHasArrayType for SqlI256 {
+        fn array_type_info() -> PgTypeInfo {
+            <String as PgHasArrayType>::array_type_info()
+        }
+
+        fn array_compatible(ty: &PgTypeInfo) -> bool {
+            <String as PgHasArrayType>::array_compatible(ty)
+        }
+    }
+
+    impl PgHasArrayType for SqlSignature {
+        fn array_type_info() -> PgTypeInfo {
+            <String as PgHasArrayType>::array_type_info()
+        }
+
+        fn array_compatible(ty: &PgTypeInfo) -> bool {
+            <String as PgHasArrayType>::array_compatible(ty)
+        }
+    }
+
+    impl PgHasArrayType for SqlBloom {
+        fn array_type_info() -> PgTypeInfo {
+            <String as PgHasArrayType>::array_type_info()
+        }
+
+        fn array_compatible(ty: &PgTypeInfo) -> bool {
+            <String as PgHasArrayType>::array_compatible(ty)
+        }
+    }
+
+    impl<const BYTES: usize> PgHasArrayType for SqlFixedBytes<BYTES> {
+        fn array_type_info() -> PgTypeInfo {
+            <String as PgHasArrayType>::array_type_info()
+        }
+
+        fn array_compatible(ty: &PgTypeInfo) -> bool {
+            <String as PgHasArrayType>::array_compatible(ty)
+        }
+    }
+
+    impl PgHasArrayType for SqlBytes {
+        fn array_type_info() -> PgTypeInfo {
+            <String as PgHasArrayType>::array_type_info()
+        }
+
+        fn array_compatible(ty: &PgTypeInfo) -> bool {
+            <String as PgHasArrayType>::array_compatible(ty)
+        }
+    }
+}
+
+/// Parses a fixed-bytes column value, with or without a `0x`/`0X` prefix.
+fn parse_fixed_bytes<const BYTES: usize>(s: String) -> Result<SqlFixedBytes<BYTES>, DecodeError> {
+    let digits = s
+        .strip_prefix("0x")
+        .or_else(|| s.strip_prefix("0X"))
+        .unwrap_or(&s);
+    if digits.len() != BYTES * 2 {
+        return Err(DecodeError::FixedBytesDecodeError(format!(
+            "{} (expected {} hex digits, got {})",
+            s,
+            BYTES * 2,
+            digits.len()
+        )));
+    }
+    SqlFixedBytes::<BYTES>::from_str(digits).map_err(|_| DecodeError::FixedBytesDecodeError(s))
+}
+
+// for SqlSignature
+impl<DB: Database> Type<DB> for SqlSignature
+where
+    String: Type<DB>,
+{
+    fn type_info() -> DB::TypeInfo {
+        <String as Type<DB>>::type_info()
+    }
+
+    fn compatible(ty: &DB::TypeInfo) -> bool {
+        <String as Type<DB>>::compatible(ty)
+    }
+}
+
+impl<'a, DB: Database> Encode<'a, DB> for SqlSignature
+where
+    String: Encode<'a, DB>,
+{
+    fn encode_by_ref(
+        &self,
+        buf: &mut <DB as Database>::ArgumentBuffer<'a>,
+    ) -> Result<IsNull, BoxDynError> {
+        self.to_string().encode_by_ref(buf)
+    }
+}
+
+impl<'a, DB: Database> Decode<'a, DB> for SqlSignature
+where
+    String: Decode<'a, DB>,
+{
+    fn decode(value: <DB as Database>::ValueRef<'a>) -> Result<Self, BoxDynError> {
+        let s = String::decode(value)?;
+        Ok(parse_fixed_bytes::<65>(s)?.into())
+    }
+}
+
+// for SqlBloom
+impl<DB: Database> Type<DB> for SqlBloom
+where
+    String: Type<DB>,
+{
+    fn type_info() -> DB::TypeInfo {
+        <String as Type<DB>>::type_info()
+    }
+
+    fn compatible(ty: &DB::TypeInfo) -> bool {
+        <String as Type<DB>>::compatible(ty)
+    }
+}
+
+impl<'a, DB: Database> Encode<'a, DB> for SqlBloom
+where
+    String: Encode<'a, DB>,
+{
+    fn encode_by_ref(
+        &self,
+        buf: &mut <DB as Database>::ArgumentBuffer<'a>,
+    ) -> Result<IsNull, BoxDynError> {
+        self.to_string().encode_by_ref(buf)
+    }
+}
+
+impl<'a, DB: Database> Decode<'a, DB> for SqlBloom
+where
+    String: Decode<'a, DB>,
+{
+    fn decode(value: <DB as Database>::ValueRef<'a>) -> Result<Self, BoxDynError> {
+        let s = String::decode(value)?;
+        Ok(parse_fixed_bytes::<256>(s)?.into())
     }
 }
 
@@ -194,3 +418,354 @@ where
         SqlBytes::from_str(&s).map_err(|e| DecodeError::BytesDecodeError(e.to_string()).into())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        sqladdress, SqlAddress, SqlBloom, SqlFixedBytes, SqlHash, SqlI256, SqlSignature, SqlU128,
+        SqlU256,
+    };
+    use sqlx::sqlite::SqlitePoolOptions;
+    use sqlx::{Row, SqlitePool};
+    use std::str::FromStr;
+
+    async fn memory_pool() -> SqlitePool {
+        SqlitePoolOptions::new()
+            .max_connections(1)
+            .connect("sqlite::memory:")
+            .await
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_address_storage_string_round_trip() {
+        let pool = memory_pool().await;
+        sqlx::query("CREATE TABLE t (addr VARCHAR(42) NOT NULL)")
+            .execute(&pool)
+            .await
+            .unwrap();
+
+        let addr = sqladdress!("0x742d35Cc6635C0532925a3b8D42cC72b5c2A9A1d");
+        sqlx::query("INSERT INTO t (addr) VALUES (?)")
+            .bind(addr)
+            .execute(&pool)
+            .await
+            .unwrap();
+
+        let row = sqlx::query("SELECT addr FROM t")
+            .fetch_one(&pool)
+            .await
+            .unwrap();
+        let raw: String = row.get("addr");
+        assert_eq!(addr.to_storage_string(), raw);
+
+        let decoded: SqlAddress = row.get("addr");
+        assert_eq!(decoded, addr);
+    }
+
+    #[tokio::test]
+    async fn test_try_get_by_index() {
+        let pool = memory_pool().await;
+        sqlx::query("CREATE TABLE t (addr VARCHAR(42) NOT NULL, amount VARCHAR(66) NOT NULL)")
+            .execute(&pool)
+            .await
+            .unwrap();
+
+        let addr = sqladdress!("0x742d35Cc6635C0532925a3b8D42cC72b5c2A9A1d");
+        let amount = SqlU256::from(1_000_000u64);
+        sqlx::query("INSERT INTO t (addr, amount) VALUES (?, ?)")
+            .bind(addr)
+            .bind(amount)
+            .execute(&pool)
+            .await
+            .unwrap();
+        sqlx::query("INSERT INTO t (addr, amount) VALUES ('not-an-address', 'oops')")
+            .execute(&pool)
+            .await
+            .unwrap();
+
+        let rows = sqlx::query("SELECT addr, amount FROM t ORDER BY rowid")
+            .fetch_all(&pool)
+            .await
+            .unwrap();
+
+        assert_eq!(rows[0].try_get::<SqlAddress, _>(0).unwrap(), addr);
+        assert_eq!(rows[0].try_get::<SqlU256, _>(1).unwrap(), amount);
+
+        // Malformed values surface as a decode error instead of panicking.
+        assert!(matches!(
+            rows[1].try_get::<SqlAddress, _>(0),
+            Err(sqlx::Error::ColumnDecode { index, .. }) if index == "0"
+        ));
+        assert!(matches!(
+            rows[1].try_get::<SqlU256, _>(1),
+            Err(sqlx::Error::ColumnDecode { .. })
+        ));
+        assert!(matches!(
+            rows[0].try_get::<SqlAddress, _>(2),
+            Err(sqlx::Error::ColumnIndexOutOfBounds { .. })
+        ));
+    }
+
+    #[cfg(feature = "postgres")]
+    #[tokio::test]
+    async fn test_postgres_unnest_array_bind() {
+        fn assert_array_bindable<'q, T>()
+        where
+            T: sqlx::Encode<'q, sqlx::Postgres> + sqlx::Type<sqlx::Postgres>,
+        {
+        }
+        assert_array_bindable::<Vec<SqlU256>>();
+        assert_array_bindable::<&[SqlU256]>();
+        assert_array_bindable::<Vec<SqlAddress>>();
+        assert_array_bindable::<Vec<SqlHash>>();
+
+        // Needs a live server; skipped unless POSTGRES_DATABASE_URL is set.
+        let Ok(url) = std::env::var("POSTGRES_DATABASE_URL") else {
+            return;
+        };
+        let pool = sqlx::postgres::PgPoolOptions::new()
+            .max_connections(1)
+            .connect(&url)
+            .await
+            .unwrap();
+        sqlx::query("CREATE TEMPORARY TABLE unnest_amounts (amount VARCHAR(66) NOT NULL)")
+            .execute(&pool)
+            .await
+            .unwrap();
+
+        let values: Vec<SqlU256> = (0..1000u64).map(|i| SqlU256::from(i * i)).collect();
+        sqlx::query("INSERT INTO unnest_amounts (amount) SELECT * FROM UNNEST($1::text[])")
+            .bind(&values)
+            .execute(&pool)
+            .await
+            .unwrap();
+
+        let mut read: Vec<SqlU256> = sqlx::query_scalar("SELECT amount FROM unnest_amounts")
+            .fetch_all(&pool)
+            .await
+            .unwrap();
+        read.sort();
+        assert_eq!(read, values);
+    }
+
+    #[tokio::test]
+    async fn test_u128_round_trip() {
+        let pool = memory_pool().await;
+        sqlx::query("CREATE TABLE t (amount VARCHAR(34) NOT NULL)")
+            .execute(&pool)
+            .await
+            .unwrap();
+
+        let max = SqlU128::from_str(&u128::MAX.to_string()).unwrap();
+        sqlx::query("INSERT INTO t (amount) VALUES (?)")
+            .bind(max)
+            .execute(&pool)
+            .await
+            .unwrap();
+        // Decimal rows are read as well, and out-of-range values fail to decode.
+        sqlx::query(
+            "INSERT INTO t (amount) VALUES ('42'), ('0x1ffffffffffffffffffffffffffffffff')",
+        )
+        .execute(&pool)
+        .await
+        .unwrap();
+
+        let rows = sqlx::query("SELECT amount FROM t ORDER BY rowid")
+            .fetch_all(&pool)
+            .await
+            .unwrap();
+        let raw: String = rows[0].get(0);
+        assert_eq!(raw, format!("0x{}", "f".repeat(32)));
+        assert_eq!(rows[0].get::<SqlU128, _>(0), max);
+        assert_eq!(
+            rows[1].get::<SqlU128, _>(0),
+            SqlU128::from_str("42").unwrap()
+        );
+        assert!(matches!(
+            rows[2].try_get::<SqlU128, _>(0),
+            Err(sqlx::Error::ColumnDecode { .. })
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_i256_round_trip() {
+        let pool = memory_pool().await;
+        sqlx::query("CREATE TABLE t (delta VARCHAR(78) NOT NULL)")
+            .execute(&pool)
+            .await
+            .unwrap();
+
+        let values = [
+            SqlI256::from(-1_000_000i64),
+            SqlI256::ZERO,
+            SqlI256::MIN,
+            SqlI256::MAX,
+        ];
+        for value in values {
+            sqlx::query("INSERT INTO t (delta) VALUES (?)")
+                .bind(value)
+                .execute(&pool)
+                .await
+                .unwrap();
+        }
+        sqlx::query("INSERT INTO t (delta) VALUES ('-0x2a'), ('not a number')")
+            .execute(&pool)
+            .await
+            .unwrap();
+
+        let rows = sqlx::query("SELECT delta FROM t ORDER BY rowid")
+            .fetch_all(&pool)
+            .await
+            .unwrap();
+        let raw: String = rows[0].get(0);
+        assert_eq!(raw, "-1000000");
+        for (row, value) in rows.iter().zip(values) {
+            assert_eq!(row.get::<SqlI256, _>(0), value);
+        }
+        assert_eq!(rows[4].get::<SqlI256, _>(0), SqlI256::from(-42i64));
+        assert!(matches!(
+            rows[5].try_get::<SqlI256, _>(0),
+            Err(sqlx::Error::ColumnDecode { .. })
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_fixed_bytes_of_any_width() {
+        let pool = memory_pool().await;
+        sqlx::query("CREATE TABLE t (selector VARCHAR(10) NOT NULL, hash VARCHAR(66) NOT NULL)")
+            .execute(&pool)
+            .await
+            .unwrap();
+
+        let selector = SqlFixedBytes::<4>::from_str("0xa9059cbb").unwrap();
+        let hash = SqlHash::splat(0xab);
+        sqlx::query("INSERT INTO t (selector, hash) VALUES (?, ?)")
+            .bind(selector)
+            .bind(hash)
+            .execute(&pool)
+            .await
+            .unwrap();
+
+        let row = sqlx::query("SELECT selector, hash FROM t")
+            .fetch_one(&pool)
+            .await
+            .unwrap();
+        let raw: String = row.get("selector");
+        assert_eq!(raw, "0xa9059cbb");
+        assert_eq!(row.get::<SqlFixedBytes<4>, _>("selector"), selector);
+        assert_eq!(row.get::<SqlHash, _>("hash"), hash);
+
+        // The width is checked against the target type.
+        assert!(row.try_get::<SqlFixedBytes<8>, _>("selector").is_err());
+        assert!(row.try_get::<SqlFixedBytes<4>, _>("hash").is_err());
+    }
+
+    #[tokio::test]
+    async fn test_fixed_bytes_decode_with_and_without_prefix() {
+        let pool = memory_pool().await;
+        sqlx::query("CREATE TABLE t (hash VARCHAR(66) NOT NULL)")
+            .execute(&pool)
+            .await
+            .unwrap();
+
+        let digits = "ddf252ad1be2c89b69c2b068fc378daa952ba7f163c4a11628f55a4df523b3ef";
+        let expected = SqlHash::from_str(digits).unwrap();
+        for raw in [
+            format!("0x{}", digits),
+            digits.to_string(),
+            format!("0X{}", digits.to_uppercase()),
+            "0x1234".to_string(),
+            format!("{}00", digits),
+        ] {
+            sqlx::query("INSERT INTO t (hash) VALUES (?)")
+                .bind(raw)
+                .execute(&pool)
+                .await
+                .unwrap();
+        }
+
+        let rows = sqlx::query("SELECT hash FROM t ORDER BY rowid")
+            .fetch_all(&pool)
+            .await
+            .unwrap();
+        assert_eq!(rows[0].try_get::<SqlHash, _>(0).unwrap(), expected);
+        assert_eq!(rows[1].try_get::<SqlHash, _>(0).unwrap(), expected);
+        assert_eq!(rows[2].try_get::<SqlHash, _>(0).unwrap(), expected);
+
+        // Wrong lengths are rejected with a message naming the expected width.
+        for row in &rows[3..] {
+            match row.try_get::<SqlHash, _>(0) {
+                Err(sqlx::Error::ColumnDecode { source, .. }) => {
+                    assert!(source.to_string().contains("expected 64 hex digits"));
+                }
+                other => panic!("expected a decode error, got {:?}", other),
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn test_signature_round_trip() {
+        let pool = memory_pool().await;
+        sqlx::query("CREATE TABLE signatures (sig CHAR(132) NOT NULL)")
+            .execute(&pool)
+            .await
+            .unwrap();
+
+        let mut raw = [0u8; 65];
+        raw[..32].fill(0x11);
+        raw[32..64].fill(0x22);
+        raw[64] = 27;
+        let sig = SqlSignature::new(raw);
+        sqlx::query("INSERT INTO signatures (sig) VALUES (?)")
+            .bind(sig)
+            .execute(&pool)
+            .await
+            .unwrap();
+        // A 64-byte value must not decode as a signature.
+        sqlx::query("INSERT INTO signatures (sig) VALUES (?)")
+            .bind(format!("0x{}", "11".repeat(64)))
+            .execute(&pool)
+            .await
+            .unwrap();
+
+        let rows = sqlx::query("SELECT sig FROM signatures ORDER BY rowid")
+            .fetch_all(&pool)
+            .await
+            .unwrap();
+        let stored: String = rows[0].get(0);
+        assert_eq!(stored.len(), 132);
+        let read: SqlSignature = rows[0].get(0);
+        assert_eq!(read, sig);
+        assert_eq!(read.v(), 27);
+        assert!(matches!(
+            rows[1].try_get::<SqlSignature, _>(0),
+            Err(sqlx::Error::ColumnDecode { .. })
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_bloom_round_trip() {
+        let pool = memory_pool().await;
+        sqlx::query("CREATE TABLE blocks (logs_bloom CHAR(514) NOT NULL)")
+            .execute(&pool)
+            .await
+            .unwrap();
+
+        let token = sqladdress!("0xdAC17F958D2ee523a2206206994597C13D831ec7");
+        let mut bloom = SqlBloom::ZERO;
+        bloom.accrue_address(&token);
+        sqlx::query("INSERT INTO blocks (logs_bloom) VALUES (?)")
+            .bind(bloom)
+            .execute(&pool)
+            .await
+            .unwrap();
+
+        let read: SqlBloom = sqlx::query_scalar("SELECT logs_bloom FROM blocks")
+            .fetch_one(&pool)
+            .await
+            .unwrap();
+        assert_eq!(read, bloom);
+        assert!(read.contains_address(&token));
+    }
+}
@@ -0,0 +1,149 @@
+//! `ToSql`/`FromSql` for the [`tokio-postgres`](https://crates.io/crates/tokio-postgres) crate.
+//!
+//! Values are stored as text in the same format as the sqlx integration, so a column
+//! written through one driver can be read through the other. Use `TEXT`/`VARCHAR` columns.
+#![cfg_attr(docsrs, doc(cfg(feature = "tokio-postgres")))]
+
+use crate::{PaddedU256, SqlAddress, SqlU256};
+use bytes::BytesMut;
+use std::error::Error;
+use std::str::FromStr;
+use tokio_postgres::types::{to_sql_checked, FromSql, IsNull, ToSql, Type};
+
+type BoxError = Box<dyn Error + Sync + Send>;
+
+impl ToSql for SqlAddress {
+    fn to_sql(&self, ty: &Type, out: &mut BytesMut) -> Result<IsNull, BoxError> {
+        self.to_storage_string().as_str().to_sql(ty, out)
+    }
+
+    fn accepts(ty: &Type) -> bool {
+        <&str as ToSql>::accepts(ty)
+    }
+
+    to_sql_checked!();
+}
+
+impl<'a> FromSql<'a> for SqlAddress {
+    fn from_sql(ty: &Type, raw: &'a [u8]) -> Result<Self, BoxError> {
+        let s = <&str as FromSql>::from_sql(ty, raw)?;
+        Ok(SqlAddress::from_str(s)?)
+    }
+
+    fn accepts(ty: &Type) -> bool {
+        <&str as FromSql>::accepts(ty)
+    }
+}
+
+impl ToSql for SqlU256 {
+    fn to_sql(&self, ty: &Type, out: &mut BytesMut) -> Result<IsNull, BoxError> {
+        self.to_string().to_lowercase().as_str().to_sql(ty, out)
+    }
+
+    fn accepts(ty: &Type) -> bool {
+        <&str as ToSql>::accepts(ty)
+    }
+
+    to_sql_checked!();
+}
+
+impl<'a> FromSql<'a> for SqlU256 {
+    fn from_sql(ty: &Type, raw: &'a [u8]) -> Result<Self, BoxError> {
+        let s = <&str as FromSql>::from_sql(ty, raw)?;
+        Ok(SqlU256::from_str(s)?)
+    }
+
+    fn accepts(ty: &Type) -> bool {
+        <&str as FromSql>::accepts(ty)
+    }
+}
+
+impl ToSql for PaddedU256 {
+    fn to_sql(&self, ty: &Type, out: &mut BytesMut) -> Result<IsNull, BoxError> {
+        self.to_string().as_str().to_sql(ty, out)
+    }
+
+    fn accepts(ty: &Type) -> bool {
+        <&str as ToSql>::accepts(ty)
+    }
+
+    to_sql_checked!();
+}
+
+impl<'a> FromSql<'a> for PaddedU256 {
+    fn from_sql(ty: &Type, raw: &'a [u8]) -> Result<Self, BoxError> {
+        SqlU256::from_sql(ty, raw).map(PaddedU256::from)
+    }
+
+    fn accepts(ty: &Type) -> bool {
+        <&str as FromSql>::accepts(ty)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sqladdress;
+
+    #[test]
+    fn test_text_encoding() {
+        let addr = sqladdress!("0x742d35Cc6635C0532925a3b8D42cC72b5c2A9A1d");
+        let mut buf = BytesMut::new();
+        assert!(matches!(addr.to_sql(&Type::TEXT, &mut buf), Ok(IsNull::No)));
+        assert_eq!(&buf[..], b"0x742d35cc6635c0532925a3b8d42cc72b5c2a9a1d");
+        assert_eq!(SqlAddress::from_sql(&Type::TEXT, &buf).unwrap(), addr);
+
+        let amount = SqlU256::from(255u64);
+        let mut buf = BytesMut::new();
+        amount.to_sql(&Type::VARCHAR, &mut buf).unwrap();
+        assert_eq!(SqlU256::from_sql(&Type::VARCHAR, &buf).unwrap(), amount);
+        assert!(SqlU256::from_sql(&Type::TEXT, b"-1").is_err());
+
+        let mut buf = BytesMut::new();
+        PaddedU256::from(amount)
+            .to_sql(&Type::TEXT, &mut buf)
+            .unwrap();
+        assert_eq!(buf.len(), 66);
+        assert_eq!(PaddedU256::from_sql(&Type::TEXT, &buf).unwrap().0, amount);
+
+        assert!(<SqlAddress as ToSql>::accepts(&Type::TEXT));
+        assert!(!<SqlU256 as ToSql>::accepts(&Type::INT8));
+    }
+
+    #[tokio::test]
+    async fn test_postgres_round_trip() {
+        // Needs a live server; skipped unless POSTGRES_DATABASE_URL is set.
+        let Ok(url) = std::env::var("POSTGRES_DATABASE_URL") else {
+            return;
+        };
+        let (client, connection) = tokio_postgres::connect(&url, tokio_postgres::NoTls)
+            .await
+            .unwrap();
+        tokio::spawn(connection);
+
+        client
+            .batch_execute(
+                "CREATE TEMPORARY TABLE tp_balances (owner TEXT NOT NULL, balance TEXT NOT NULL)",
+            )
+            .await
+            .unwrap();
+
+        let owner = sqladdress!("0x742d35Cc6635C0532925a3b8D42cC72b5c2A9A1d");
+        let balance = SqlU256::from(10u64).pow(18);
+        client
+            .execute(
+                "INSERT INTO tp_balances (owner, balance) VALUES ($1, $2)",
+                &[&owner, &balance],
+            )
+            .await
+            .unwrap();
+
+        let row = client
+            .query_one("SELECT owner, balance FROM tp_balances", &[])
+            .await
+            .unwrap();
+        assert_eq!(row.get::<_, SqlAddress>(0), owner);
+        assert_eq!(row.get::<_, SqlU256>(1), balance);
+        assert_eq!(row.get::<_, String>(0), owner.to_storage_string());
+    }
+}
@@ -1,10 +1,12 @@
 //! Utilities for parsing and formatting SqlU256 with decimals (e.g. for ERC20/ETH amounts).
 
-use crate::SqlU256;
+use crate::{SqlAddress, SqlU256};
 use alloy::primitives::{
-    utils::{format_units, parse_units, UnitsError},
-    U256,
+    ruint::ParseError,
+    utils::{format_units, parse_units, ParseUnits, Unit, UnitsError},
+    ParseSignedError, U256,
 };
+use std::str::FromStr;
 
 /// Parses a decimal string (e.g. "1.23") into a SqlU256, given the number of decimals.
 ///
@@ -42,3 +44,346 @@ pub fn parse_sether(s: &str) -> Result<SqlU256, UnitsError> {
 pub fn format_sether(value: SqlU256) -> Result<String, UnitsError> {
     format_suint(value, 18)
 }
+
+/// Rounding strategy for [`format_suint_rounded`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RoundMode {
+    /// Round half away from zero (`0.125` -> `0.13`).
+    HalfUp,
+    /// Round half to even, a.k.a. banker's rounding (`0.125` -> `0.12`).
+    HalfEven,
+}
+
+/// Formats a SqlU256 with `decimals` decimals, rounded to `display_decimals` fractional digits.
+///
+/// Unlike [`format_suint`], which prints every decimal, this rounds the dropped digits
+/// according to `mode`.
+///
+/// # Examples
+/// ```
+/// use ethereum_mysql::utils::{format_suint_rounded, parse_suint, RoundMode};
+/// let v = parse_suint("1.005", 6).unwrap();
+/// assert_eq!(format_suint_rounded(v, 6, 2, RoundMode::HalfUp), "1.01");
+/// assert_eq!(format_suint_rounded(v, 6, 2, RoundMode::HalfEven), "1.00");
+/// ```
+pub fn format_suint_rounded(
+    value: SqlU256,
+    decimals: u8,
+    display_decimals: u8,
+    mode: RoundMode,
+) -> String {
+    let shown = display_decimals.min(decimals);
+    let value = value.into_inner();
+    let scaled = match U256::from(10u64).checked_pow(U256::from(decimals - shown)) {
+        Some(divisor) => {
+            let (quotient, remainder) = value.div_rem(divisor);
+            let half = divisor / U256::from(2u64);
+            let round_up = if divisor == U256::from(1u64) {
+                false
+            } else {
+                match mode {
+                    RoundMode::HalfUp => remainder >= half,
+                    RoundMode::HalfEven => {
+                        remainder > half || (remainder == half && quotient.bit(0))
+                    }
+                }
+            };
+            if round_up {
+                // Only overflows when rounding U256::MAX itself upward.
+                quotient.saturating_add(U256::from(1u64))
+            } else {
+                quotient
+            }
+        }
+        // 10^(decimals - shown) exceeds U256, so every value rounds down to zero.
+        None => U256::ZERO,
+    };
+
+    let digits = scaled.to_string();
+    let mut out = if shown == 0 {
+        digits
+    } else {
+        let shown = shown as usize;
+        let padded = format!("{:0>width$}", digits, width = shown + 1);
+        let (int_part, frac_part) = padded.split_at(padded.len() - shown);
+        format!("{}.{}", int_part, frac_part)
+    };
+    if display_decimals > decimals {
+        if decimals == 0 {
+            out.push('.');
+        }
+        out.extend(std::iter::repeat_n(
+            '0',
+            (display_decimals - decimals) as usize,
+        ));
+    }
+    out
+}
+
+/// Checks a column of address strings, returning the `(row index, value)` of each entry
+/// that does not parse as an address.
+///
+/// Useful in migration scripts to report bad data before switching a column to
+/// [`SqlAddress`].
+///
+/// # Examples
+/// ```
+/// use ethereum_mysql::utils::validate_address_column;
+/// let rows = vec![
+///     "0x742d35Cc6635C0532925a3b8D42cC72b5c2A9A1d".to_string(),
+///     "not-an-address".to_string(),
+/// ];
+/// assert_eq!(validate_address_column(rows.into_iter()), vec![(1, "not-an-address".to_string())]);
+/// ```
+pub fn validate_address_column(values: impl Iterator<Item = String>) -> Vec<(usize, String)> {
+    values
+        .enumerate()
+        .filter(|(_, value)| SqlAddress::from_str(value).is_err())
+        .collect()
+}
+
+/// Parses a percentage string such as `"12.5%"` into basis points (`1250`).
+///
+/// At most two fractional digits are accepted, since 1 bp is `0.01%`. Values above
+/// `100%` are rejected. Apply the result with [`SqlU256::mul_div`](crate::SqlU256::mul_div)
+/// and a denominator of `10_000`.
+///
+/// # Examples
+/// ```
+/// use ethereum_mysql::utils::parse_percent_bps;
+/// assert_eq!(parse_percent_bps("12.5%"), Ok(1250));
+/// assert_eq!(parse_percent_bps("0.01%"), Ok(1));
+/// assert!(parse_percent_bps("150%").is_err());
+/// ```
+pub fn parse_percent_bps(s: &str) -> Result<u32, &'static str> {
+    let number = s
+        .trim()
+        .strip_suffix('%')
+        .ok_or("percent string must end with '%'")?
+        .trim_end();
+    let (int_part, frac_part) = number.split_once('.').unwrap_or((number, ""));
+    let all_digits = |p: &str| p.bytes().all(|b| b.is_ascii_digit());
+    if int_part.is_empty() || !all_digits(int_part) || !all_digits(frac_part) {
+        return Err("invalid percent string");
+    }
+    if frac_part.len() > 2 {
+        return Err("percent string has more than two decimal places");
+    }
+    let whole: u32 = int_part.parse().map_err(|_| "percent value above 100%")?;
+    let frac: u32 = format!("{:0<2}", frac_part).parse().unwrap_or(0);
+    match whole.checked_mul(100).and_then(|v| v.checked_add(frac)) {
+        Some(bps) if bps <= 10_000 => Ok(bps),
+        _ => Err("percent value above 100%"),
+    }
+}
+
+/// Inserts `separator` between every group of three digits of a decimal integer string.
+fn group_digits(digits: &str, separator: char) -> String {
+    let mut out = String::with_capacity(digits.len() + digits.len() / 3);
+    for (i, c) in digits.chars().enumerate() {
+        if i > 0 && (digits.len() - i).is_multiple_of(3) {
+            out.push(separator);
+        }
+        out.push(c);
+    }
+    out
+}
+
+impl SqlU256 {
+    /// Formats the value as a decimal string with `,` thousands separators.
+    ///
+    /// # Examples
+    /// ```
+    /// use ethereum_mysql::SqlU256;
+    /// assert_eq!(SqlU256::from(1000000u64).to_grouped_string(), "1,000,000");
+    /// ```
+    pub fn to_grouped_string(&self) -> String {
+        group_digits(&self.inner().to_string(), ',')
+    }
+
+    /// Formats the value as a decimal string with the given number of decimals,
+    /// grouping the integer part with `separator`.
+    ///
+    /// # Examples
+    /// ```
+    /// use ethereum_mysql::utils::parse_sether;
+    /// let v = parse_sether("1234.5").unwrap();
+    /// assert_eq!(v.to_grouped_units(18, ',').unwrap(), "1,234.500000000000000000");
+    /// ```
+    pub fn to_grouped_units(&self, decimals: u8, separator: char) -> Result<String, UnitsError> {
+        let formatted = format_suint(*self, decimals)?;
+        Ok(match formatted.split_once('.') {
+            Some((int_part, frac_part)) => {
+                format!("{}.{}", group_digits(int_part, separator), frac_part)
+            }
+            None => group_digits(&formatted, separator),
+        })
+    }
+
+    /// Parses an amount followed by a unit name (e.g. `"1.5 ether"`, `"20 gwei"`) into wei.
+    ///
+    /// Unit names follow alloy's [`Unit`] (`wei`, `kwei`, `mwei`, `gwei`, `szabo`, `finney`,
+    /// `ether`, ...), case-insensitively. Negative amounts are rejected.
+    ///
+    /// # Examples
+    /// ```
+    /// use ethereum_mysql::SqlU256;
+    /// assert_eq!(SqlU256::parse_with_unit("20 gwei").unwrap(), SqlU256::from(20_000_000_000u64));
+    /// assert!(SqlU256::parse_with_unit("1 bitcoin").is_err());
+    /// ```
+    pub fn parse_with_unit(s: &str) -> Result<SqlU256, UnitsError> {
+        let mut parts = s.split_whitespace();
+        let (Some(amount), Some(unit), None) = (parts.next(), parts.next(), parts.next()) else {
+            return Err(UnitsError::InvalidUnit(s.to_string()));
+        };
+        let unit: Unit = unit.parse()?;
+        match ParseUnits::parse_units(amount, unit)? {
+            ParseUnits::U256(value) => Ok(SqlU256::from(value)),
+            ParseUnits::I256(_) => Err(UnitsError::ParseSigned(ParseSignedError::Ruint(
+                ParseError::InvalidDigit('-'),
+            ))),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_to_grouped_string() {
+        assert_eq!(SqlU256::from(1000000u64).to_grouped_string(), "1,000,000");
+        assert_eq!(SqlU256::from(123456u64).to_grouped_string(), "123,456");
+        assert_eq!(SqlU256::from(12345u64).to_grouped_string(), "12,345");
+        assert_eq!(SqlU256::from(999u64).to_grouped_string(), "999");
+        assert_eq!(SqlU256::ZERO.to_grouped_string(), "0");
+    }
+
+    #[test]
+    fn test_to_grouped_units() {
+        let v = parse_suint("1234567.89", 6).unwrap();
+        assert_eq!(v.to_grouped_units(6, ',').unwrap(), "1,234,567.890000");
+        assert_eq!(v.to_grouped_units(6, '_').unwrap(), "1_234_567.890000");
+        assert_eq!(SqlU256::ZERO.to_grouped_units(6, ',').unwrap(), "0.000000");
+    }
+
+    #[test]
+    fn test_parse_with_unit() {
+        assert_eq!(
+            SqlU256::parse_with_unit("1.5 ether").unwrap(),
+            SqlU256::from(1_500_000_000_000_000_000u128)
+        );
+        assert_eq!(
+            SqlU256::parse_with_unit("20 gwei").unwrap(),
+            SqlU256::from(20_000_000_000u64)
+        );
+        assert_eq!(
+            SqlU256::parse_with_unit("100 wei").unwrap(),
+            SqlU256::from(100u64)
+        );
+        assert_eq!(
+            SqlU256::parse_with_unit("  2 ETHER ").unwrap(),
+            SqlU256::from(2_000_000_000_000_000_000u128)
+        );
+
+        assert!(matches!(
+            SqlU256::parse_with_unit("1 bitcoin"),
+            Err(UnitsError::InvalidUnit(_))
+        ));
+        assert!(SqlU256::parse_with_unit("100").is_err());
+        assert!(SqlU256::parse_with_unit("1 ether extra").is_err());
+        assert!(SqlU256::parse_with_unit("-1 ether").is_err());
+    }
+
+    #[test]
+    fn test_format_suint_rounded() {
+        let v = parse_sether("1.2345").unwrap();
+        assert_eq!(format_suint_rounded(v, 18, 2, RoundMode::HalfUp), "1.23");
+        assert_eq!(format_suint_rounded(v, 18, 2, RoundMode::HalfEven), "1.23");
+        assert_eq!(format_suint_rounded(v, 18, 3, RoundMode::HalfUp), "1.235");
+        assert_eq!(format_suint_rounded(v, 18, 3, RoundMode::HalfEven), "1.234");
+
+        // Ties differ only when the kept digit is even
+        let tie = parse_sether("1.245").unwrap();
+        assert_eq!(format_suint_rounded(tie, 18, 2, RoundMode::HalfUp), "1.25");
+        assert_eq!(
+            format_suint_rounded(tie, 18, 2, RoundMode::HalfEven),
+            "1.24"
+        );
+        let odd_tie = parse_sether("1.235").unwrap();
+        assert_eq!(
+            format_suint_rounded(odd_tie, 18, 2, RoundMode::HalfEven),
+            "1.24"
+        );
+
+        // Carry into the integer part, zero display decimals, and padding
+        let carry = parse_sether("9.999").unwrap();
+        assert_eq!(
+            format_suint_rounded(carry, 18, 2, RoundMode::HalfUp),
+            "10.00"
+        );
+        assert_eq!(
+            format_suint_rounded(carry, 18, 0, RoundMode::HalfEven),
+            "10"
+        );
+        let small = parse_suint("0.5", 6).unwrap();
+        assert_eq!(
+            format_suint_rounded(small, 6, 8, RoundMode::HalfUp),
+            "0.50000000"
+        );
+        assert_eq!(
+            format_suint_rounded(SqlU256::from(5u64), 0, 2, RoundMode::HalfUp),
+            "5.00"
+        );
+    }
+
+    #[test]
+    fn test_validate_address_column() {
+        let rows = vec![
+            "0x742d35Cc6635C0532925a3b8D42cC72b5c2A9A1d",
+            "0x742d35cc6635c0532925a3b8d42cc72b5c2a9a1d",
+            "",
+            "0x1234",
+            "742d35cc6635c0532925a3b8d42cc72b5c2a9a1d",
+            "0xzz2d35cc6635c0532925a3b8d42cc72b5c2a9a1d",
+        ];
+        let bad = validate_address_column(rows.into_iter().map(String::from));
+        assert_eq!(
+            bad,
+            vec![
+                (2, String::new()),
+                (3, "0x1234".to_string()),
+                (5, "0xzz2d35cc6635c0532925a3b8d42cc72b5c2a9a1d".to_string()),
+            ]
+        );
+        assert!(validate_address_column(std::iter::empty()).is_empty());
+    }
+
+    #[test]
+    fn test_parse_percent_bps() {
+        assert_eq!(parse_percent_bps("0%"), Ok(0));
+        assert_eq!(parse_percent_bps("100%"), Ok(10_000));
+        assert_eq!(parse_percent_bps("0.01%"), Ok(1));
+        assert_eq!(parse_percent_bps("12.5%"), Ok(1250));
+        assert_eq!(parse_percent_bps(" 3.25 %"), Ok(325));
+        assert_eq!(parse_percent_bps("100.00%"), Ok(10_000));
+
+        // Over 100% is rejected
+        assert!(parse_percent_bps("100.01%").is_err());
+        assert!(parse_percent_bps("250%").is_err());
+        assert!(parse_percent_bps("99999999999%").is_err());
+
+        assert!(parse_percent_bps("12.5").is_err());
+        assert!(parse_percent_bps("0.001%").is_err());
+        assert!(parse_percent_bps("-1%").is_err());
+        assert!(parse_percent_bps(".5%").is_err());
+        assert!(parse_percent_bps("%").is_err());
+        assert!(parse_percent_bps("1e2%").is_err());
+
+        let fee = SqlU256::from(1_000_000u64).mul_div(
+            SqlU256::from(parse_percent_bps("0.3%").unwrap()),
+            SqlU256::from(10_000u64),
+        );
+        assert_eq!(fee, Some(SqlU256::from(3_000u64)));
+    }
+}